@@ -1,5 +1,9 @@
 from enum import Enum
-from typing import List, Optional
+from typing import TYPE_CHECKING, Callable, Iterator, List, NoReturn, Optional, Tuple, Union
+
+# numpy ist optional und wird nur für die Typen von *to_numpy* und *to_planes* gebraucht
+if TYPE_CHECKING:
+    import numpy
 
 class Coordinate:
     """
@@ -40,6 +44,55 @@ class Coordinate:
             bool: Das Ergebnis des Vergleichs.
         """
         ...
+    def __hash__(self) -> int: ...
+    def __lt__(self, other: Coordinate) -> bool:
+        """
+        Ordnet nach x, dann y, damit Listen deterministisch sortiert werden können.
+        """
+        ...
+    def __le__(self, other: Coordinate) -> bool: ...
+    def __gt__(self, other: Coordinate) -> bool: ...
+    def __ge__(self, other: Coordinate) -> bool: ...
+    def __copy__(self) -> Coordinate: ...
+    def __deepcopy__(self, memo: dict) -> Coordinate: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], Coordinate], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
+        """
+        ...
+
+    @staticmethod
+    def from_bytes(data: bytes) -> Coordinate:
+        """
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
+
+        Args:
+            data (bytes): Die binäre Kodierung.
+
+        Returns:
+            Coordinate: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
+
+    def transform(self, symmetry: Symmetry) -> Coordinate:
+        """
+        Wendet eine Symmetrie des Spielbretts an.
+
+        Args:
+            symmetry (Symmetry): Die Symmetrie.
+
+        Returns:
+            Coordinate: Das Ergebnis der Symmetrie.
+        """
+        ...
 
     def deepcopy(self) -> Coordinate: 
         """
@@ -119,6 +172,36 @@ class Vector:
             bool: Das Ergebnis des Vergleichs.
         """
         ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> Vector: ...
+    def __deepcopy__(self, memo: dict) -> Vector: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], Vector], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
+        """
+        ...
+
+    @staticmethod
+    def from_bytes(data: bytes) -> Vector:
+        """
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
+
+        Args:
+            data (bytes): Die binäre Kodierung.
+
+        Returns:
+            Vector: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
+
         
     def deepcopy(self) -> GameState: 
         """
@@ -242,6 +325,47 @@ class Direction(Enum):
             bool: Das Ergebnis des Vergleichs.
         """
         ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> Direction: ...
+    def __deepcopy__(self, memo: dict) -> Direction: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], Direction], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
+        """
+        ...
+
+    @staticmethod
+    def from_bytes(data: bytes) -> Direction:
+        """
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
+
+        Args:
+            data (bytes): Die binäre Kodierung.
+
+        Returns:
+            Direction: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
+
+    def transform(self, symmetry: Symmetry) -> Direction:
+        """
+        Wendet eine Symmetrie des Spielbretts an.
+
+        Args:
+            symmetry (Symmetry): Die Symmetrie.
+
+        Returns:
+            Direction: Das Ergebnis der Symmetrie.
+        """
+        ...
 
     @staticmethod
     def from_vector(vector: Vector) -> Optional[Direction]:
@@ -350,6 +474,35 @@ class FieldType(Enum):
             bool: Das Ergebnis des Vergleichs.
         """
         ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> FieldType: ...
+    def __deepcopy__(self, memo: dict) -> FieldType: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], FieldType], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
+        """
+        ...
+
+    @staticmethod
+    def from_bytes(data: bytes) -> FieldType:
+        """
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
+
+        Args:
+            data (bytes): Die binäre Kodierung.
+
+        Returns:
+            FieldType: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
 
     def get_value(self) -> int:
         """
@@ -420,6 +573,35 @@ class TeamEnum(Enum):
             bool: Das Ergebnis des Vergleichs.
         """
         ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> TeamEnum: ...
+    def __deepcopy__(self, memo: dict) -> TeamEnum: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], TeamEnum], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
+        """
+        ...
+
+    @staticmethod
+    def from_bytes(data: bytes) -> TeamEnum:
+        """
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
+
+        Args:
+            data (bytes): Die binäre Kodierung.
+
+        Returns:
+            TeamEnum: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
 
     def get_fish_types(self) -> List[FieldType]:
         """
@@ -446,13 +628,42 @@ class Board:
     Das Feld unten-links hat Koordinate (0, 0) und das Feld oben-rechts ist an Position (9, 9).<br>
     Gleichzeitig bedeutet das, dass map[0] auch die unterste Zeile des Spielfeldes ist.
 
+    Intern wird das Spielbrett als Bitboards gespeichert (je Team und Fischgröße sowie für die Kraken).
+    Die Liste *map* wird bei jedem Zugriff daraus neu berechnet.<br>
+    **Achtung**: Änderungen an der zurückgegebenen Liste wirken sich nicht auf das Spielbrett aus.
+    Stattdessen muss *map* neu zugewiesen oder *GameState.set_board_field()* verwendet werden.
+    Eine Liste, die nicht genau 10x10 Felder hat, wird sowohl im Konstruktor als auch beim Zuweisen von *map*
+    mit einem ValueError abgelehnt.
+
     Attributes:
         map (List[List[Field]]): Die 2 dimensionale Liste der Felder, die das Spielbrett darstellen.<br>
     """
 
     map: List[List[FieldType]]
+    zobrist: int
+    """
+    Der 64 Bit Zobrist-Hash des Spielbretts, der bei jeder Änderung eines Feldes mitgeführt wird (nur lesbar).
+    """
 
     def __init__(self, map: List[List[FieldType]]) -> None: ...
+
+    @staticmethod
+    def random_start(seed: Optional[int] = None) -> Board:
+        """
+        Erzeugt ein zufälliges Startbrett.
+
+        Team One steht in der linken und rechten Spalte, Team Two in der unteren und oberen Zeile, die Ecken bleiben frei.
+        Jedes Team hat 7 kleine, 5 mittlere und 4 große Fische, wobei Team Two die an der Diagonalen gespiegelte
+        Aufstellung von Team One erhält. Die zwei Kraken liegen punktsymmetrisch im inneren Bereich,
+        nicht auf den Diagonalen und nicht nebeneinander.
+
+        Args:
+            seed (Optional[int]): Derselbe Seed ergibt dasselbe Brett. Ohne Angabe wird die aktuelle Zeit verwendet.
+
+        Returns:
+            Board: Das Startbrett.
+        """
+        ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
     
@@ -480,6 +691,60 @@ class Board:
             bool: Das Ergebnis des Vergleichs.
         """
         ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> Board: ...
+    def __deepcopy__(self, memo: dict) -> Board: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], Board], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
+        """
+        ...
+
+    @staticmethod
+    def from_bytes(data: bytes) -> Board:
+        """
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
+
+        Args:
+            data (bytes): Die binäre Kodierung.
+
+        Returns:
+            Board: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
+
+    def transform(self, symmetry: Symmetry) -> Board:
+        """
+        Wendet eine Symmetrie des Spielbretts an.
+        Symmetrien, die die Achsen tauschen, tauschen auch die Teams, sodass jedes Team auf seinen Startseiten bleibt.
+
+        Args:
+            symmetry (Symmetry): Die Symmetrie.
+
+        Returns:
+            Board: Das Ergebnis der Symmetrie.
+        """
+        ...
+
+    def to_numpy(self) -> "numpy.ndarray":
+        """
+        Wandelt das Spielbrett in Merkmalsebenen für neuronale Netze um.
+        Benötigt das Paket `numpy`.
+
+        Returns:
+            numpy.ndarray: Ein float32-Array der Form (8, 10, 10) mit einer Ebene pro *FieldType*
+            in der Reihenfolge der Deklaration, jeweils indiziert mit [y][x].
+        """
+        ...
+
         
     def deepcopy(self) -> GameState: 
         """
@@ -558,275 +823,2409 @@ class Board:
         """
         ...
 
-class Move:
+class Swarm:
     """
-    Repräsentiert einen Zug im Spiel.
+    Ein Schwarm aus zusammenhängenden Fischen eines Teams.
 
     Attribute:
-        start (Coordinate): Die Koordinate, von wo aus ein Fisch bewegt werden soll.
-        direction (Direction): Die Richtung, in die der Fisch schwimmt.
+        team (TeamEnum): Das Team, dem die Fische gehören.
+        members (List[Coordinate]): Die Positionen der Fische im Schwarm.
+        value (int): Die Summe der Fischgrößen im Schwarm.
     """
 
-    start: Coordinate
-    direction: Direction
+    team: TeamEnum
+    members: List[Coordinate]
+    value: int
 
-    def __init__(self, start: Coordinate, direction: Direction) -> None: ...
+    def __init__(self, board: Board, team: TeamEnum, members: List[Coordinate]) -> None: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
-    
-    def __eq__(self, other: Move) -> bool:
+    def __len__(self) -> int: ...
+    def __eq__(self, other: Swarm) -> bool: ...
+    def __ne__(self, other: Swarm) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> Swarm: ...
+    def __deepcopy__(self, memo: dict) -> Swarm: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], Swarm], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
         """
-        Unterstützt den Vergleichsoperator ==, um die Werte mit denen eines
-        weiteren Objektes zuvergleichen.
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
+        """
+        ...
+
+    @staticmethod
+    def from_bytes(data: bytes) -> Swarm:
+        """
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
 
         Args:
-            other: (Move): Die andere Koordinate.
+            data (bytes): Die binäre Kodierung.
 
         Returns:
-            bool: Das Ergebnis des Vergleichs.
+            Swarm: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
         """
         ...
-    def __ne__(self, other: Move) -> bool:
+
+    def deepcopy(self) -> Swarm:
         """
-        Unterstützt den Vergleichsoperator !=, um die Werte mit denen eines
-        weiteren Objektes zuvergleichen.
+        Kopiert das Objekt rekursiv.
+        """
+        ...
+
+    def size(self) -> int:
+        """
+        Gibt die Anzahl der Fische im Schwarm zurück.
+
+        Returns:
+            int: Die Anzahl der Fische.
+        """
+        ...
+
+    def contains(self, position: Coordinate) -> bool:
+        """
+        Prüft, ob ein Fisch an der Position zu diesem Schwarm gehört.
 
         Args:
-            other: (Move): Die andere Koordinate.
+            position (Coordinate): Die Position.
 
         Returns:
-            bool: Das Ergebnis des Vergleichs.
+            bool: Ob die Position im Schwarm liegt.
         """
         ...
-        
-    def deepcopy(self) -> GameState: 
+
+    def bounding_box(self) -> Optional[Tuple[Coordinate, Coordinate]]:
         """
-        Kopiert das Objekt rekursiv.
+        Berechnet das kleinste achsenparallele Rechteck, das alle Fische des Schwarms enthält.
+
+        Returns:
+            Optional[Tuple[Coordinate, Coordinate]]: Die Ecke unten-links und die Ecke oben-rechts, oder None, wenn der Schwarm leer ist.
         """
         ...
 
-class GameState:
+class Move:
     """
-    Repräsentiert einen Spielstand.
+    Repräsentiert einen Zug im Spiel.
 
     Attribute:
-        board (Board): Das Spielbrett.
-        turn (int): Die aktuelle Runde.
-        last_move (Optional[Move]): Der zuletzt ausgeführte Zug.
+        start (Coordinate): Die Koordinate, von wo aus ein Fisch bewegt werden soll.
+        direction (Direction): Die Richtung, in die der Fisch schwimmt.
     """
 
-    board: Board
-    turn: int
-    last_move: Optional[Move]
+    start: Coordinate
+    direction: Direction
 
-    def __init__(self, board: Board, turn: int, last_move: Optional[Move]) -> None: ...
+    def __init__(self, start: Coordinate, direction: Direction) -> None: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
-    def __eq__(self, other: GameState) -> bool:
+    
+    def __eq__(self, other: Move) -> bool:
         """
         Unterstützt den Vergleichsoperator ==, um die Werte mit denen eines
         weiteren Objektes zuvergleichen.
 
         Args:
-            other: (GameState): Die andere Koordinate.
+            other: (Move): Die andere Koordinate.
 
         Returns:
             bool: Das Ergebnis des Vergleichs.
         """
         ...
-    def __ne__(self, other: GameState) -> bool:
+    def __ne__(self, other: Move) -> bool:
         """
         Unterstützt den Vergleichsoperator !=, um die Werte mit denen eines
         weiteren Objektes zuvergleichen.
 
         Args:
-            other: (GameState): Die andere Koordinate.
+            other: (Move): Die andere Koordinate.
 
         Returns:
             bool: Das Ergebnis des Vergleichs.
         """
         ...
-
-    def deepcopy(self) -> GameState: 
+    def __hash__(self) -> int: ...
+    def __lt__(self, other: Move) -> bool:
         """
-        Kopiert das Objekt rekursiv.
+        Ordnet nach Startkoordinate, dann Richtung, damit Listen deterministisch sortiert werden können.
         """
         ...
+    def __le__(self, other: Move) -> bool: ...
+    def __gt__(self, other: Move) -> bool: ...
+    def __ge__(self, other: Move) -> bool: ...
+    def __copy__(self) -> Move: ...
+    def __deepcopy__(self, memo: dict) -> Move: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], Move], Tuple[bytes]]: ...
 
-    def set_board_field(self, position: Coordinate, field: FieldType) -> None:
+    def to_bytes(self) -> bytes:
         """
-        Ändert ein Feld auf dem Spielfeld an einer Koordinate.
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
 
-        Args:
-            position (Coordinate): Die Position des Feldes, das geändert werden soll.
-            field (FieldType): Das Feld, was dort platziert werden soll.
+        Returns:
+            bytes: Die binäre Kodierung.
         """
         ...
 
-    def possible_moves_for(self, start: Coordinate) -> List[Move]:
+    @staticmethod
+    def from_bytes(data: bytes) -> Move:
         """
-        Berechnet alle Züge, die aus der aktuellen Spielposition für den Fisch an der Koordinate möglich sind.
-        
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
+
         Args:
-            start (Coordinate): Die Position des gewählten Fisch.
+            data (bytes): Die binäre Kodierung.
 
         Returns:
-            List[Move]: Die Liste der Züge.
+            Move: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
         """
         ...
 
-    def possible_moves(self) -> List[Move]:
+    def to_notation(self, board: Optional[Board] = None) -> str:
         """
-        Berechnet alle Züge, die aus der aktuellen Spielposition für den aktuellen Spieler möglich sind.
+        Schreibt den Zug kurz mit Startfeld und Richtung, z.B. `a4>R`.
+        Die Spalten a bis j sind x = 0 bis 9, die Zeilen 1 bis 10 sind y = 0 bis 9,
+        die Richtungen heißen U, UR, R, DR, D, DL, L und UL.
+
+        Args:
+            board (Optional[Board]): Mit Spielbrett wird das Ziel angehängt, z.B. `a4>R=c4`.
 
         Returns:
-            List[Move]: Die Liste der Züge.
+            str: Der Zug in Notation.
+
+        Raises:
+            StartOutOfBoundsError: Wenn der Start außerhalb des Spielfelds liegt.
+            TargetOutOfBoundsError: Wenn das Ziel auf dem Spielbrett außerhalb des Spielfelds liegt.
         """
         ...
 
-    def perform_move(self, move: Move) -> GameState:
+    @staticmethod
+    def parse(notation: str, board: Optional[Board] = None) -> Move:
         """
-        Führt den gegebenen Zug auf dem Spielstand aus, insofern dieser ausführbar ist (**nicht mutierend**).
-        Dabei wird *kein* Zug an den Spielserver übermittelt.
+        Liest einen Zug wie `a4>R`, `a4>R=c4` oder `A4 UP_RIGHT`.
+        Richtungen können auch ausgeschrieben werden, in beliebiger Groß- und Kleinschreibung.
 
         Args:
-            move_ (Move): Der zuverwendene Zug.
+            notation (str): Der Zug in Notation.
+            board (Optional[Board]): Wenn angegeben, muss ein angegebenes Ziel dem tatsächlichen Ziel auf diesem Brett entsprechen.
 
         Returns:
-            Gamestate: Der neue Spielstand.
+            Move: Der gelesene Zug.
 
         Raises:
-            PiranhasError: Wenn der Zug nicht valide ist.
+            InvalidNotationError: Wenn die Notation ungültig ist oder das Ziel nicht passt.
         """
         ...
 
-    def perform_move_mut(self, move: Move) -> None:
+    def transform(self, symmetry: Symmetry) -> Move:
         """
-        Führt den gegebenen Zug auf dem Spielstand aus, insofern dieser ausführbar ist (**mutierend**).
+        Wendet eine Symmetrie des Spielbretts an.
+
+        Args:
+            symmetry (Symmetry): Die Symmetrie.
+
+        Returns:
+            Move: Das Ergebnis der Symmetrie.
+        """
+        ...
+
+    def to_index(self) -> int:
+        """
+        Bildet den Zug auf den Aktionsraum 10x10x8 ab: (y * 10 + x) * 8 plus die Richtung in der Reihenfolge der Deklaration.
+
+        Returns:
+            int: Der Index zwischen 0 und 799.
+
+        Raises:
+            StartOutOfBoundsError: Wenn der Start außerhalb des Spielfelds liegt.
+        """
+        ...
+
+    @staticmethod
+    def from_index(index: int) -> Move:
+        """
+        Die Umkehrung von *to_index*.
+
+        Args:
+            index (int): Der Index zwischen 0 und 799.
+
+        Returns:
+            Move: Der Zug zu diesem Index.
+
+        Raises:
+            ValueError: Wenn der Index außerhalb des Aktionsraums liegt.
+        """
+        ...
+
+        
+    def deepcopy(self) -> GameState: 
+        """
+        Kopiert das Objekt rekursiv.
+        """
+        ...
+
+class UndoToken:
+    """
+    Enthält alles, was nötig ist, um einen Zug mit *GameState.unmake_move* zurückzunehmen.
+
+    Attribute:
+        move_ (Move): Der ausgeführte Zug.
+        target (Coordinate): Das Zielfeld des Zuges.
+        captured (FieldType): Das Feld, das vor dem Zug auf dem Zielfeld lag.
+        last_move (Optional[Move]): Der letzte Zug vor diesem Zug.
+        turn (int): Die Runde vor diesem Zug.
+    """
+
+    move_: Move
+    target: Coordinate
+    captured: FieldType
+    last_move: Optional[Move]
+    turn: int
+
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: UndoToken) -> bool: ...
+    def __ne__(self, other: UndoToken) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> UndoToken: ...
+    def __deepcopy__(self, memo: dict) -> UndoToken: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], UndoToken], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
+        """
+        ...
+
+    @staticmethod
+    def from_bytes(data: bytes) -> UndoToken:
+        """
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
+
+        Args:
+            data (bytes): Die binäre Kodierung.
+
+        Returns:
+            UndoToken: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
+
+
+class GameState:
+    """
+    Repräsentiert einen Spielstand.
+
+    Attribute:
+        board (Board): Das Spielbrett.
+        turn (int): Die aktuelle Runde.
+        last_move (Optional[Move]): Der zuletzt ausgeführte Zug.
+    """
+
+    board: Board
+    turn: int
+    last_move: Optional[Move]
+    zobrist: int
+    """
+    Der 64 Bit Zobrist-Hash des Spielstands aus Spielbrett und Team am Zug (nur lesbar).<br>
+    Gleiche Stellungen haben unabhängig von der Zugfolge denselben Hash, *last_move* fließt nicht ein.
+    Geeignet als Schlüssel für Transpositionstabellen oder Python-Dictionaries.
+    """
+
+    def __init__(self, board: Board, turn: int, last_move: Optional[Move]) -> None: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: GameState) -> bool:
+        """
+        Unterstützt den Vergleichsoperator ==, um die Werte mit denen eines
+        weiteren Objektes zuvergleichen.
+
+        Args:
+            other: (GameState): Die andere Koordinate.
+
+        Returns:
+            bool: Das Ergebnis des Vergleichs.
+        """
+        ...
+    def __ne__(self, other: GameState) -> bool:
+        """
+        Unterstützt den Vergleichsoperator !=, um die Werte mit denen eines
+        weiteren Objektes zuvergleichen.
+
+        Args:
+            other: (GameState): Die andere Koordinate.
+
+        Returns:
+            bool: Das Ergebnis des Vergleichs.
+        """
+        ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> GameState: ...
+    def __deepcopy__(self, memo: dict) -> GameState: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], GameState], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
+        """
+        ...
+
+    @staticmethod
+    def from_bytes(data: bytes) -> GameState:
+        """
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
+
+        Args:
+            data (bytes): Die binäre Kodierung.
+
+        Returns:
+            GameState: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
+
+    def to_notation(self) -> str:
+        """
+        Schreibt den Spielstand in eine Zeile, ähnlich zu FEN im Schach, z.B. für Fehlerberichte und Tests.
+
+        Die Zeilen des Spielbretts stehen von oben (y = 9) nach unten (y = 0), getrennt durch '/'.
+        Fische von Team One sind S, M und L, die von Team Two s, m und l, Kraken sind *, und Ziffern zählen leere Felder.
+        Danach folgen der Zug und der letzte Zug wie `a4>R`, oder '-' ohne letzten Zug.
+        Felder heißen wie im Schach, die Spalten a bis j sind x = 0 bis 9 und die Zeilen 1 bis 10 sind y = 0 bis 9.
+
+        Returns:
+            str: Der Spielstand, z.B. `1msllmmss1/S8S/L2*5S/S8M/M8M/S8L/L8L/S5*2S/M8M/1mslsmsls1 0 -`.
+
+        Raises:
+            StartOutOfBoundsError: Wenn der letzte Zug außerhalb des Spielfelds startet.
+        """
+        ...
+
+    @staticmethod
+    def from_notation(notation: str) -> GameState:
+        """
+        Liest einen Spielstand aus der Notation von *to_notation*.
+        Zug und letzter Zug können am Anfang eines Spiels weggelassen werden.
+
+        Args:
+            notation (str): Der Spielstand in Notation.
+
+        Returns:
+            GameState: Der gelesene Spielstand.
+
+        Raises:
+            InvalidNotationError: Wenn die Notation ungültig ist.
+        """
+        ...
+
+    def to_json(self) -> str:
+        """
+        Schreibt den Spielstand als JSON, z.B. für Analysen und Trainingsdaten.
+        Nur vorhanden, wenn das Paket mit dem Feature `serde` gebaut wurde (`maturin build --features serde`).
+        Das Spielbrett ist eine Liste von Zeilen von y = 0 bis 9 mit den Namen der Feldtypen,
+        Züge bestehen aus `start` mit `x` und `y` und dem Namen der `direction`.
+
+        Returns:
+            str: Der Spielstand als JSON.
+        """
+        ...
+
+    @staticmethod
+    def from_json(json: str) -> GameState:
+        """
+        Liest einen Spielstand aus dem JSON von *to_json*.
+        Nur vorhanden, wenn das Paket mit dem Feature `serde` gebaut wurde.
+
+        Args:
+            json (str): Der Spielstand als JSON.
+
+        Returns:
+            GameState: Der gelesene Spielstand.
+
+        Raises:
+            ValueError: Wenn das JSON ungültig ist.
+        """
+        ...
+
+    def transform(self, symmetry: Symmetry) -> GameState:
+        """
+        Wendet eine Symmetrie des Spielbretts an.
+        Symmetrien, die die Teams tauschen, ergeben keinen gleichwertigen Spielstand, da dann das andere Team am Zug wäre.
+        Für diese bleibt nur Board.transform.
+
+        Args:
+            symmetry (Symmetry): Die Symmetrie.
+
+        Returns:
+            GameState: Das Ergebnis der Symmetrie.
+
+        Raises:
+            PiranhasError: Wenn die Symmetrie die Teams tauscht.
+        """
+        ...
+
+    def canonical(self) -> Tuple[GameState, Symmetry]:
+        """
+        Bestimmt den kleinsten Spielstand unter den Symmetrien, die die Teams nicht tauschen.
+        Alle gleichwertigen Spielstände haben denselben Vertreter, z.B. für Eröffnungsbücher.
+
+        Returns:
+            Tuple[GameState, Symmetry]: Der Vertreter und die Symmetrie, die zu ihm führt.
+        """
+        ...
+
+    def to_planes(self) -> "numpy.ndarray":
+        """
+        Wandelt den Spielstand in Merkmalsebenen für neuronale Netze um.
+        Benötigt das Paket `numpy`.
+
+        Returns:
+            numpy.ndarray: Ein float32-Array der Form (10, 10, 10): die Ebenen von *Board.to_numpy*,
+            eine Ebene mit Einsen, wenn Team One am Zug ist, und eine Ebene mit dem gespielten Anteil des Rundenlimits.
+        """
+        ...
+
+    def deepcopy(self) -> GameState: 
+        """
+        Kopiert das Objekt rekursiv.
+        """
+        ...
+
+    def set_board_field(self, position: Coordinate, field: FieldType) -> None:
+        """
+        Ändert ein Feld auf dem Spielfeld an einer Koordinate.
+
+        Args:
+            position (Coordinate): Die Position des Feldes, das geändert werden soll.
+            field (FieldType): Das Feld, was dort platziert werden soll.
+        """
+        ...
+
+    def possible_moves_for(self, start: Coordinate) -> List[Move]:
+        """
+        Berechnet alle Züge, die aus der aktuellen Spielposition für den Fisch an der Koordinate möglich sind.
+        
+        Args:
+            start (Coordinate): Die Position des gewählten Fisch.
+
+        Returns:
+            List[Move]: Die Liste der Züge.
+        """
+        ...
+
+    def possible_moves(self) -> List[Move]:
+        """
+        Berechnet alle Züge, die aus der aktuellen Spielposition für den aktuellen Spieler möglich sind.
+
+        Returns:
+            List[Move]: Die Liste der Züge.
+        """
+        ...
+
+    def perform_move(self, move: Move) -> GameState:
+        """
+        Führt den gegebenen Zug auf dem Spielstand aus, insofern dieser ausführbar ist (**nicht mutierend**).
+        Dabei wird *kein* Zug an den Spielserver übermittelt.
+
+        Args:
+            move_ (Move): Der zuverwendene Zug.
+
+        Returns:
+            Gamestate: Der neue Spielstand.
+
+        Raises:
+            GameOverError: Wenn das Spiel bereits vorbei ist.
+            WrongTeamError: Wenn der Fisch nicht dem Team am Zug gehört.
+            PiranhasError: Wenn der Zug nicht valide ist.
+        """
+        ...
+
+    def perform_move_mut(self, move: Move) -> None:
+        """
+        Führt den gegebenen Zug auf dem Spielstand aus, insofern dieser ausführbar ist (**mutierend**).
         Dabei wird *kein* Zug an den Spielserver übermittelt.
 
         Args:
-            move_ (Move): Der zuverwendene Zug.
+            move_ (Move): Der zuverwendene Zug.
+
+        Raises:
+            PiranhasError: Wenn der Zug nicht valide ist.
+        """
+        ...
+
+    def validate_move(self, move: Move, last_move: Optional[Move] = None) -> None:
+        """
+        Prüft einen Zug so, wie es der Spielserver tut.<br>
+        Neben den Regeln für den Zug selbst wird geprüft, ob der Fisch dem Team am Zug gehört
+        und ob das Spiel noch läuft.
+
+        Gibt keinen Wert zurück, sondern wirft eine Fehlermeldung, falls der Zug nicht valide ist.
+
+        Args:
+            move (Move): Der Zug, der geprüft werden soll.
+            last_move (Optional[Move]): Der letzte Zug des Spielstands, für den der Zug berechnet wurde.
+                Wird er angegeben und weicht vom *last_move* dieses Spielstands ab, ist der Zug veraltet.
+
+        Raises:
+            GameOverError: Wenn das Spiel bereits vorbei ist.
+            StaleLastMoveError: Wenn der Zug für einen anderen Spielstand berechnet wurde.
+            WrongTeamError: Wenn der Fisch nicht dem Team am Zug gehört.
+            PiranhasError: Wenn der Zug aus einem anderen Grund nicht valide ist.
+        """
+        ...
+
+    def is_legal(self, move: Move) -> bool:
+        """
+        Prüft wie *validate_move*, ob ein Zug im aktuellen Spielstand erlaubt ist.
+
+        Args:
+            move (Move): Der Zug, der geprüft werden soll.
+
+        Returns:
+            bool: Ob der Zug erlaubt ist.
+        """
+        ...
+
+    def make_move(self, move: Move) -> UndoToken:
+        """
+        Führt den gegebenen Zug auf dem Spielstand aus (**mutierend**) und gibt zurück, wie er sich rückgängig machen lässt.
+        Im Gegensatz zu *perform_move* wird dabei kein neuer Spielstand angelegt, was Tiefensuchen deutlich beschleunigt.
+
+        Args:
+            move (Move): Der zuverwendene Zug.
+
+        Returns:
+            UndoToken: Die Informationen, um den Zug mit *unmake_move* zurückzunehmen.
+
+        Raises:
+            GameOverError: Wenn das Spiel bereits vorbei ist.
+            PiranhasError: Wenn der Zug nicht valide ist.
+        """
+        ...
+
+    def unmake_move(self, token: UndoToken) -> None:
+        """
+        Nimmt einen mit *make_move* ausgeführten Zug zurück (**mutierend**).
+        Mehrere Züge müssen in umgekehrter Reihenfolge zurückgenommen werden.
+
+        Args:
+            token (UndoToken): Das von *make_move* zurückgegebene Token des letzten Zuges.
+
+        Raises:
+            StaleUndoTokenError: Wenn das Token nicht zum letzten Zug gehört.
+        """
+        ...
+
+    def perft(self, depth: int) -> int:
+        """
+        Zählt die Spielstände, die nach genau `depth` Zügen erreicht werden.
+        Beendete Spiele werden nicht weitergespielt.
+
+        Args:
+            depth (int): Die Anzahl der Züge.
+
+        Returns:
+            int: Die Anzahl der erreichten Spielstände.
+        """
+        ...
+
+    def divide(self, depth: int) -> List[Tuple[Move, int]]:
+        """
+        Wie *perft*, aber getrennt nach dem ersten Zug, um Unterschiede in der Zuggenerierung zu finden.
+
+        Args:
+            depth (int): Die Anzahl der Züge inklusive des ersten.
+
+        Returns:
+            List[Tuple[Move, int]]: Jeder mögliche Zug mit der Anzahl der Spielstände darunter.
+        """
+        ...
+
+    def is_over(self) -> bool:
+        """
+        Prüft, ob das Spiel in diesem Spielstand beendet ist.<br>
+        Das ist der Fall, wenn nach einer vollständigen Runde ein Team alle Fische zu einem Schwarm
+        verbunden hat, oder wenn das Rundenlimit erreicht ist.
+
+        Returns:
+            bool: Ob das Spiel vorbei ist.
+        """
+        ...
+
+    def winner(self) -> Optional[TeamEnum]:
+        """
+        Gibt den Gewinner des Spiels zurück.
+
+        Returns:
+            Optional[TeamEnum]: Das Team, das gewonnen hat, oder None, wenn das Spiel noch läuft oder unentschieden ist.
+        """
+        ...
+
+    def game_result(self) -> Optional[GameResult]:
+        """
+        Berechnet das Ergebnis des Spiels nach den Endbedingungen.
+
+        Schafft es genau ein Team, alle Fische zu einem Schwarm zu verbinden, gewinnt es.
+        Schaffen es beide Teams in derselben Runde, oder ist das Rundenlimit erreicht,
+        gewinnt das Team mit dem wertvolleren größten Schwarm. Sind beide Werte gleich, ist es unentschieden.
+
+        Returns:
+            Optional[GameResult]: Das Ergebnis, oder None, wenn das Spiel noch nicht vorbei ist.
+        """
+        ...
+
+class WinReason(Enum):
+    """
+    Der Grund, warum ein Spiel beendet wurde.
+    """
+
+    SwarmConnected = 0
+    """
+    Ein Team hat alle Fische zu einem Schwarm verbunden.
+    """
+    BothConnected = 1
+    """
+    Beide Teams haben in derselben Runde ihre Fische verbunden, der Schwarmwert entscheidet.
+    """
+    RoundLimit = 2
+    """
+    Das Rundenlimit wurde erreicht, der Schwarmwert entscheidet.
+    """
+    Timeout = 3
+    """
+    Der Gegner hat das Zeitlimit eines Zuges überschritten.
+    """
+    IllegalMove = 4
+    """
+    Der Gegner hat einen ungültigen oder keinen Zug gesendet.
+    """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: WinReason) -> bool: ...
+    def __ne__(self, other: WinReason) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> WinReason: ...
+    def __deepcopy__(self, memo: dict) -> WinReason: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], WinReason], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
+        """
+        ...
+
+    @staticmethod
+    def from_bytes(data: bytes) -> WinReason:
+        """
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
+
+        Args:
+            data (bytes): Die binäre Kodierung.
+
+        Returns:
+            WinReason: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
+
+
+class GameResult:
+    """
+    Das Ergebnis eines beendeten Spiels.
+
+    Attribute:
+        winner (Optional[TeamEnum]): Das Gewinnerteam, oder None bei einem Unentschieden.
+        reason (WinReason): Der Grund für das Spielende.
+        team_one_score (int): Der Wert des größten Schwarms von Team 1.
+        team_two_score (int): Der Wert des größten Schwarms von Team 2.
+    """
+
+    winner: Optional[TeamEnum]
+    reason: WinReason
+    team_one_score: int
+    team_two_score: int
+
+    def __init__(self, winner: Optional[TeamEnum], reason: WinReason, team_one_score: int, team_two_score: int) -> None: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: GameResult) -> bool: ...
+    def __ne__(self, other: GameResult) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> GameResult: ...
+    def __deepcopy__(self, memo: dict) -> GameResult: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], GameResult], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
+        """
+        ...
+
+    @staticmethod
+    def from_bytes(data: bytes) -> GameResult:
+        """
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
+
+        Args:
+            data (bytes): Die binäre Kodierung.
+
+        Returns:
+            GameResult: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
+
+    def deepcopy(self) -> GameResult:
+        """
+        Kopiert das Objekt rekursiv.
+        """
+        ...
+
+    def is_draw(self) -> bool:
+        """
+        Gibt an, ob das Spiel unentschieden ausgegangen ist.
+
+        Returns:
+            bool: Ob es keinen Gewinner gibt.
+        """
+        ...
+
+    def score_of(self, team: TeamEnum) -> int:
+        """
+        Gibt die Punkte eines Teams zurück.
+
+        Args:
+            team (TeamEnum): Das Team.
+
+        Returns:
+            int: Der Wert des größten Schwarms des Teams.
+        """
+        ...
+
+class RulesEngine:
+    """
+    Stellt Methoden, die zur Überprüfung der Spielregeln dienen.
+    """
+
+    @staticmethod
+    def move_distance(board: Board, move_: Move) -> int:
+        """
+        Gibt die Länge / Anzahl der Felder von einem Zug auf dem Spielfeld zurück.
+
+        Args:
+            board (Board): Das Spielfeld, auf dem die Länge berechnet werden soll.
+            move_ (Move): Der zuverwendene Zug.
+
+        Returns:
+            int: Die Länge.
+        """
+        ...
+
+    @staticmethod
+    def target_position(board: Board, move_: Move) -> Coordinate:
+        """
+        Gibt die Koordinate zurück, auf der ein Fisch landen würde, wenn man den Zug ausführt.
+
+        Es wird nicht berücksichtigt, ob diese Koordinate im Spielfeld ist.
+
+        Args:
+            board (Board): Das Spielfeld, auf dem der Zug berechnet werden soll.
+            move_ (Move): Der zuverwendene Zug.
+
+        Returns:
+            Coordinate: Die Koordinate.
+        """
+        ...
+
+    @staticmethod
+    def is_in_bounds(coordinate: Coordinate) -> bool:
+        """
+        Gibt einen Wahrheitswert zurück, ob eine Position in dem (Standard-) Spielfeld (10x10) liegt.
+
+        Args:
+            coordinate (Coordinate): Die Position
+
+        Returns:
+            bool: Ob die Koordinate im Feld ist.
+        """
+        ...
+
+    @staticmethod
+    def can_execute_move(board: Board, move_: Move) -> None:
+        """
+        Prüft, ob ein Zug auf dem Board nach den Regeln durchgeführt werden könnte.<br>
+        Dabei ist nicht relevant, welcher Spieler gerade tatsächlich dran wäre.
+
+        Gibt keinen Wert zurück, sondern wirft eine Fehlermeldung, falls der Zug nicht valide ist.
+
+        Args:
+            board (Board): Das Spielfeld.
+            move_ (Move): Der Zug, der geprüft werden soll.
+
+        Raises:
+            PiranhasError: Wenn der Zug nicht valide ist. Die genaue Ursache ist an der Unterklasse zu erkennen,
+                z.B. *BlockedBySquidError* oder *JumpsOverOpponentError*.
+        """
+        ...
+
+    @staticmethod 
+    def get_team_on_turn(turn: int) -> TeamEnum:
+        """
+        Berechnet anhand der Zugzahl, welcher Spieler dran sein müsste.<br>
+        Es wird nicht beachtet, ob die Zahl kleiner 0 oder größer 59 ist.
+
+        Args:
+            turn (int): Die Zugzahl.
+
+        Returns:
+            TeamEnum: Das Team, was dran ist.
+        """
+        ...
+
+    @staticmethod
+    def swarm_from(board: Board, position: Coordinate) -> List[Coordinate]:
+        """
+        Berechnet auf einem Spielbrett von einer Startposition aus, welche Fische
+        von dort aus in einem Schwarm zusammenhängen.
+        Dabei werden nur Fische beachtet, die im selben Team sind, wie der auf dem Startfeld.
+        Gibt eine Liste an Koordinaten zurück, die leer ist, wenn die Startposition
+        außerhalb des Spielbrettes ist, oder kein Fisch als Start angegeben ist.
+
+        Args:
+            board (Board): Das Spielbrett.
+            position (Coordinate): Die Startkoordinate
+
+        Returns:
+            List[Coordinate]: Die Liste an zusammenhängenden Fischen.
+
+        """
+        ...
+
+    @staticmethod
+    def swarms_of_team(board: Board, team: TeamEnum) -> List[List[Coordinate]]:
+        """
+        Berechnet auf einem Spielbrett alle Schwärme, die ein Team gerade gebildet hat.
+        Gibt eine 2-Dimensionale Liste zurück, wobei jede Sub-Liste ein einzelner Schwarm ist.
+
+        Args:
+            board (Board): Das Spielbrett.
+            team (TeamEnum): Das gewählte Team.
+
+        Returns:
+            List[List[Coordinate]]: Die Liste an Schwärmen.
+
+        """
+        ...
+
+    @staticmethod
+    def is_swarm_connected(board: Board, team: TeamEnum) -> bool:
+        """
+        Prüft, ob alle Fische eines Teams einen einzigen Schwarm bilden.
+
+        Args:
+            board (Board): Das Spielbrett.
+            team (TeamEnum): Das gewählte Team.
+
+        Returns:
+            bool: Ob das Team genau einen Schwarm hat.
+        """
+        ...
+
+    @staticmethod
+    def swarm_values(board: Board, team: TeamEnum) -> List[Swarm]:
+        """
+        Berechnet alle Schwärme eines Teams zusammen mit ihrem Wert.<br>
+        Die Liste ist absteigend nach Wert sortiert, bei gleichem Wert kommt der größere Schwarm zuerst.
+
+        Args:
+            board (Board): Das Spielbrett.
+            team (TeamEnum): Das gewählte Team.
+
+        Returns:
+            List[Swarm]: Die Liste an Schwärmen.
+        """
+        ...
+
+    @staticmethod
+    def largest_swarm_value(board: Board, team: TeamEnum) -> int:
+        """
+        Berechnet den Wert des wertvollsten Schwarms eines Teams.<br>
+        Der Wert eines Schwarms ist die Summe der Fischgrößen (siehe *FieldType.get_value()*).
+
+        Args:
+            board (Board): Das Spielbrett.
+            team (TeamEnum): Das gewählte Team.
+
+        Returns:
+            int: Der Wert, oder 0, wenn das Team keine Fische mehr hat.
+        """
+        ...
+
+    @staticmethod
+    def compare_swarms(board: Board) -> Optional[TeamEnum]:
+        """
+        Vergleicht die Schwärme beider Teams nach der offiziellen Wertung,
+        also nach dem Wert des jeweils größten Schwarms.
+        Bei gleichem Wert entscheidet seine Größe, danach werden die nächstkleineren Schwärme genauso verglichen.
+
+        Args:
+            board (Board): Das Spielbrett.
+
+        Returns:
+            Optional[TeamEnum]: Das Team mit dem wertvolleren Schwarm, oder None bei Gleichstand.
+        """
+        ...
+
+class Evaluator:
+    """
+    Eine Bewertungsfunktion aus gewichteten Merkmalen, die vollständig in Rust berechnet wird.
+
+    Jedes Merkmal wird für beide Teams berechnet, die Bewertung ist die gewichtete Summe
+    der Differenzen (eigenes Team minus Gegner) aus Sicht des Teams, das am Zug ist.
+    Merkmale mit Gewicht 0 tragen nichts bei, die teure Mobilität wird dann gar nicht berechnet.
+
+    Attribute:
+        swarm_size (float): Gewicht für die Anzahl der Fische im wertvollsten Schwarm.
+        largest_swarm_value (float): Gewicht für den Wert des wertvollsten Schwarms.
+        swarm_count (float): Gewicht für die Anzahl der Schwärme.
+        centre_distance (float): Gewicht für die mittlere Entfernung der Fische zur Spielfeldmitte.
+        mobility (float): Gewicht für die Anzahl der möglichen Züge.
+        squid_adjacency (float): Gewicht für die Anzahl der Fische, die an einen Kraken angrenzen.
+    """
+
+    swarm_size: float
+    largest_swarm_value: float
+    swarm_count: float
+    centre_distance: float
+    mobility: float
+    squid_adjacency: float
+
+    def __init__(
+        self,
+        swarm_size: float = 1.0,
+        largest_swarm_value: float = 1.0,
+        swarm_count: float = -2.0,
+        centre_distance: float = -1.0,
+        mobility: float = 0.0,
+        squid_adjacency: float = 0.0,
+    ) -> None: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: Evaluator) -> bool: ...
+    def __ne__(self, other: Evaluator) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> Evaluator: ...
+    def __deepcopy__(self, memo: dict) -> Evaluator: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], Evaluator], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
+        """
+        ...
+
+    @staticmethod
+    def from_bytes(data: bytes) -> Evaluator:
+        """
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
+
+        Args:
+            data (bytes): Die binäre Kodierung.
+
+        Returns:
+            Evaluator: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
+
+    def deepcopy(self) -> Evaluator:
+        """
+        Kopiert das Objekt rekursiv.
+        """
+        ...
+
+    def evaluate(self, state: GameState) -> float:
+        """
+        Bewertet einen Spielstand aus Sicht des Teams, das am Zug ist.
+
+        Args:
+            state (GameState): Der Spielstand.
+
+        Returns:
+            float: Die Bewertung, größer ist besser.
+        """
+        ...
+
+class BoundType(Enum):
+    """
+    Gibt an, wie ein gespeicherter Wert in der Transpositionstabelle zu lesen ist.
+    """
+
+    Exact = 0
+    """
+    Der Wert ist exakt.
+    """
+    Lower = 1
+    """
+    Der Wert ist eine untere Schranke (die Suche wurde an beta abgeschnitten).
+    """
+    Upper = 2
+    """
+    Der Wert ist eine obere Schranke (kein Zug war besser als alpha).
+    """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: BoundType) -> bool: ...
+    def __ne__(self, other: BoundType) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> BoundType: ...
+    def __deepcopy__(self, memo: dict) -> BoundType: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], BoundType], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
+        """
+        ...
+
+    @staticmethod
+    def from_bytes(data: bytes) -> BoundType:
+        """
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
+
+        Args:
+            data (bytes): Die binäre Kodierung.
+
+        Returns:
+            BoundType: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
+
+class TableEntry:
+    """
+    Ein Eintrag der Transpositionstabelle.
+
+    Attribute:
+        key (int): Der Zobrist-Hash des Spielstands, gemischt mit dem Zug.
+        bound (BoundType): Die Art des Werts.
+        depth (int): Die Suchtiefe, mit der der Wert berechnet wurde.
+        score (float): Der Wert aus Sicht des Teams am Zug.
+        best_move (Optional[Move]): Der beste gefundene Zug.
+    """
+
+    key: int
+    bound: BoundType
+    depth: int
+    score: float
+    best_move: Optional[Move]
+
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: TableEntry) -> bool: ...
+    def __ne__(self, other: TableEntry) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> TableEntry: ...
+    def __deepcopy__(self, memo: dict) -> TableEntry: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], TableEntry], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
+        """
+        ...
+
+    @staticmethod
+    def from_bytes(data: bytes) -> TableEntry:
+        """
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
+
+        Args:
+            data (bytes): Die binäre Kodierung.
+
+        Returns:
+            TableEntry: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
+
+class TranspositionTable:
+    """
+    Eine Transpositionstabelle fester Größe, die Spielstände über ihren Zobrist-Hash (*GameState.zobrist*) ablegt.
+    Der Zug fließt in den Schlüssel ein, da Rundenlimit und Schwarmprüfung von ihm abhängen.
+
+    Jeder Hash hat genau einen Platz in der Tabelle. Ist dieser von einem anderen Spielstand belegt,
+    wird der alte Eintrag nur ersetzt, wenn der neue mindestens so tief gesucht wurde.
+
+    Attribute:
+        hits (int): Anzahl der Abfragen, die einen Eintrag gefunden haben.
+        misses (int): Anzahl der Abfragen ohne Eintrag.
+        collisions (int): Anzahl der Abfragen, deren Platz von einem anderen Spielstand belegt war.
+        stores (int): Anzahl der gespeicherten Einträge.
+    """
+
+    hits: int
+    misses: int
+    collisions: int
+    stores: int
+
+    def __init__(self, size: int = 65536) -> None:
+        """
+        Args:
+            size (int): Die Anzahl der Plätze in der Tabelle.
+        """
+        ...
+    def __repr__(self) -> str: ...
+    def __len__(self) -> int: ...
+    def __copy__(self) -> TranspositionTable: ...
+    def __deepcopy__(self, memo: dict) -> TranspositionTable: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], TranspositionTable], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
+        """
+        ...
+
+    @staticmethod
+    def from_bytes(data: bytes) -> TranspositionTable:
+        """
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
+
+        Args:
+            data (bytes): Die binäre Kodierung.
+
+        Returns:
+            TranspositionTable: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
+
+    def size(self) -> int:
+        """
+        Gibt die Anzahl der Plätze zurück.
+        """
+        ...
+
+    def filled(self) -> int:
+        """
+        Gibt die Anzahl der belegten Plätze zurück.
+        """
+        ...
+
+    def probe(self, state: GameState) -> Optional[TableEntry]:
+        """
+        Sucht den Eintrag zu einem Spielstand.
+
+        Args:
+            state (GameState): Der Spielstand.
+
+        Returns:
+            Optional[TableEntry]: Der Eintrag, oder None, wenn der Spielstand nicht gespeichert ist.
+        """
+        ...
+
+    def store(
+        self, state: GameState, depth: int, score: float, bound: BoundType, best_move: Optional[Move] = None
+    ) -> bool:
+        """
+        Speichert einen Eintrag für einen Spielstand.
+
+        Args:
+            state (GameState): Der Spielstand.
+            depth (int): Die Suchtiefe, mit der der Wert berechnet wurde.
+            score (float): Der Wert.
+            bound (BoundType): Die Art des Werts.
+            best_move (Optional[Move]): Der beste gefundene Zug.
+
+        Returns:
+            bool: Ob der Eintrag gespeichert wurde.
+        """
+        ...
+
+    def clear(self) -> None:
+        """
+        Leert die Tabelle und setzt die Statistiken zurück.
+        """
+        ...
+
+class Searcher:
+    """
+    Eine Alpha-Beta-Suche (Negamax) mit iterativer Vertiefung und Zeitlimit.
+
+    Die Suche läuft vollständig in Rust und gibt währenddessen den GIL frei,
+    sodass andere Python-Threads weiterlaufen können.
+
+    Eine Suche kann nicht mit pickle gespeichert werden, da sie Python-Funktionen enthalten kann.
+    Stattdessen wird eine neue mit denselben Einstellungen erzeugt.
+
+    Attribute:
+        max_depth (int): Die maximale Suchtiefe in Halbzügen.
+        nodes (int): Die Anzahl der untersuchten Spielstände der letzten Suche.
+        depth (int): Die zuletzt vollständig durchsuchte Tiefe.
+    """
+
+    max_depth: int
+    nodes: int
+    depth: int
+    table: TranspositionTable
+    """
+    Eine Kopie der Transpositionstabelle, die zwischen den Suchen erhalten bleibt (nur lesbar).
+    """
+
+    def __init__(
+        self,
+        max_depth: int = 64,
+        evaluator: Optional[Union[Evaluator, Callable[[GameState], float]]] = None,
+        table_size: int = 65536,
+    ) -> None:
+        """
+        Args:
+            max_depth (int): Die maximale Suchtiefe in Halbzügen.
+            evaluator (Optional[Union[Evaluator, Callable[[GameState], float]]]): Die Bewertungsfunktion.
+                Entweder ein *Evaluator* oder eine Python-Funktion, die einen Spielstand aus Sicht des Teams
+                am Zug bewertet. Ohne Angabe wird ein *Evaluator* mit Standardgewichten verwendet.
+                Bewertungen werden auf ±998999 begrenzt, damit sie nicht als erzwungener Sieg gelten.
+            table_size (int): Die Anzahl der Plätze der Transpositionstabelle.
+
+        Raises:
+            TypeError: Wenn der evaluator weder ein *Evaluator* noch aufrufbar ist.
+        """
+        ...
+    def __repr__(self) -> str: ...
+    def __reduce__(self) -> NoReturn:
+        """
+        Raises:
+            TypeError: Immer, eine Suche kann nicht mit pickle gespeichert werden.
+        """
+        ...
+
+    def clear_table(self) -> None:
+        """
+        Leert die Transpositionstabelle.
+        """
+        ...
+
+    def search(self, state: GameState, time_ms: int) -> Optional[Tuple[Move, float, List[Move]]]:
+        """
+        Sucht den besten Zug für das Team, das im Spielstand am Zug ist.<br>
+        Die Tiefe wird so lange erhöht, bis *max_depth* oder das Zeitlimit erreicht ist.
+        Eine abgebrochene Tiefe wird verworfen, es zählt das Ergebnis der letzten vollständigen Tiefe.
+        Tiefe 1 wird immer vollständig durchsucht, auch wenn das Zeitlimit schon abgelaufen ist.
+
+        Args:
+            state (GameState): Der Spielstand, von dem aus gesucht wird.
+            time_ms (int): Das Zeitlimit in Millisekunden.
+
+        Returns:
+            Optional[Tuple[Move, float, List[Move]]]: Der beste Zug, seine Bewertung aus Sicht des Teams am Zug
+            und die erwartete Zugfolge (Hauptvariante), oder None, wenn das Spiel vorbei ist oder es keinen Zug gibt.
+
+        Raises:
+            Exception: Jeder Fehler, den eine Python-Bewertungsfunktion wirft, bricht die Suche ab und wird weitergegeben.
+            ValueError: Wenn eine Bewertung keine endliche Zahl ist, z.B. NaN.
+        """
+        ...
+
+class Playout(Enum):
+    """
+    Die Art, wie die Monte-Carlo-Suche ein Spiel zu Ende spielt.
+    """
+
+    Random = 0
+    """
+    Jeder Zug wird zufällig gewählt.
+    """
+    Heuristic = 1
+    """
+    Aus einigen zufälligen Zügen wird der gewählt, den die Bewertungsfunktion am besten bewertet.
+    """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: Playout) -> bool: ...
+    def __ne__(self, other: Playout) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> Playout: ...
+    def __deepcopy__(self, memo: dict) -> Playout: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], Playout], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
+        """
+        ...
+
+    @staticmethod
+    def from_bytes(data: bytes) -> Playout:
+        """
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
+
+        Args:
+            data (bytes): Die binäre Kodierung.
+
+        Returns:
+            Playout: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
+
+class MonteCarloSearcher:
+    """
+    Eine Monte-Carlo-Baumsuche (MCTS) mit UCT-Auswahl.
+
+    Jede Iteration wählt einen Pfad im Suchbaum, erweitert ihn um einen Zug und spielt das Spiel
+    von dort bis zum Ende (spätestens bis zum Rundenlimit). Der Suchbaum wird zwischen zwei Zügen
+    weiterverwendet, wenn der neue Spielstand über *last_move* aus dem alten hervorgeht.
+    Die Suche gibt währenddessen den GIL frei.
+
+    Wie *Searcher* kann sie nicht mit pickle gespeichert werden (TypeError).
+
+    Attribute:
+        exploration (float): Die Explorationskonstante der UCT-Formel.
+        playout (Playout): Die Art der Simulation.
+        playout_candidates (int): Wie viele zufällige Züge bei *Playout.Heuristic* pro Schritt verglichen werden.
+        iterations (int): Die Anzahl der Iterationen der letzten Suche.
+    """
+
+    exploration: float
+    playout: Playout
+    playout_candidates: int
+    iterations: int
+
+    def __init__(
+        self,
+        exploration: float = 1.4142135623730951,
+        playout: Playout = Playout.Random,
+        seed: Optional[int] = None,
+        evaluator: Optional[Union[Evaluator, Callable[[GameState], float]]] = None,
+    ) -> None:
+        """
+        Args:
+            exploration (float): Die Explorationskonstante der UCT-Formel.
+            playout (Playout): Die Art der Simulation.
+            seed (Optional[int]): Startwert für den Zufall, damit Suchen reproduzierbar sind.
+            evaluator (Optional[Union[Evaluator, Callable[[GameState], float]]]): Die Bewertungsfunktion für *Playout.Heuristic*.
+        """
+        ...
+    def __repr__(self) -> str: ...
+    def __reduce__(self) -> NoReturn:
+        """
+        Raises:
+            TypeError: Immer, eine Suche kann nicht mit pickle gespeichert werden.
+        """
+        ...
+
+    def search(
+        self, state: GameState, time_ms: Optional[int] = None, iterations: Optional[int] = None
+    ) -> Optional[Tuple[Move, float, List[Move]]]:
+        """
+        Sucht den besten Zug für das Team, das im Spielstand am Zug ist.<br>
+        Es wird gesucht, bis die Zeit oder die Anzahl an Iterationen aufgebraucht ist, je nachdem was zuerst eintritt.
+
+        Args:
+            state (GameState): Der Spielstand, von dem aus gesucht wird.
+            time_ms (Optional[int]): Das Zeitlimit in Millisekunden.
+            iterations (Optional[int]): Die maximale Anzahl an Iterationen.
+
+        Returns:
+            Optional[Tuple[Move, float, List[Move]]]: Der am häufigsten besuchte Zug, seine Gewinnrate (0 bis 1)
+            und die meistbesuchte Zugfolge, oder None, wenn das Spiel vorbei ist.
+
+        Raises:
+            ValueError: Wenn weder time_ms noch iterations angegeben ist.
+            ValueError: Wenn eine Bewertung im heuristischen Playout keine endliche Zahl ist, z.B. NaN.
+        """
+        ...
+
+    def tree_size(self) -> int:
+        """
+        Gibt die Anzahl der Knoten im Suchbaum zurück.
+
+        Returns:
+            int: Die Anzahl der Knoten.
+        """
+        ...
+
+    def clear(self) -> None:
+        """
+        Verwirft den Suchbaum.
+        """
+        ...
+
+class PiranhasError(Exception):
+    """
+    Basisklasse aller Fehler dieses Moduls.
+
+    Fehler bei der Prüfung von Zügen werden als Unterklassen geworfen und tragen die betroffenen Koordinaten:
+
+    Attribute:
+        start (Coordinate): Das Startfeld des Zuges.
+        target (Optional[Coordinate]): Das Zielfeld des Zuges, falls es für den Fehler relevant ist.
+        over (Optional[Coordinate]): Der erste gegnerische Fisch, über den der Zug springen würde.
+    """
+
+    start: Coordinate
+    target: Optional[Coordinate]
+    over: Optional[Coordinate]
+
+class StartOutOfBoundsError(PiranhasError):
+    """
+    Das Startfeld liegt außerhalb des Spielfelds.
+    """
+
+class TargetOutOfBoundsError(PiranhasError):
+    """
+    Das Zielfeld liegt außerhalb des Spielfelds.
+    """
+
+class NotAFishError(PiranhasError):
+    """
+    Auf dem Startfeld liegt kein Fisch.
+    """
+
+class WrongTeamError(PiranhasError):
+    """
+    Der Fisch auf dem Startfeld gehört nicht dem Team, das am Zug ist.
+    """
+
+class BlockedBySquidError(PiranhasError):
+    """
+    Auf dem Zielfeld liegt ein Krake.
+    """
+
+class OwnFishOnTargetError(PiranhasError):
+    """
+    Auf dem Zielfeld liegt ein Fisch des eigenen Teams.
+    """
+
+class JumpsOverOpponentError(PiranhasError):
+    """
+    Zwischen Start- und Zielfeld liegt ein Fisch des gegnerischen Teams.
+    """
+
+class GameOverError(PiranhasError):
+    """
+    Das Spiel ist bereits vorbei.
+    """
+
+class StaleLastMoveError(PiranhasError):
+    """
+    Der Zug wurde für einen anderen Spielstand berechnet, der letzte Zug stimmt nicht überein.
+
+    Attribute:
+        expected (Move): Der letzte Zug, nach dem der Zug berechnet wurde.
+        actual (Optional[Move]): Der tatsächliche letzte Zug des Spielstands.
+    """
+
+    expected: Move
+    actual: Optional[Move]
+
+class StaleUndoTokenError(PiranhasError):
+    """
+    Das Token zum Zurücknehmen gehört nicht zum letzten Zug des Spielstands.
+    """
+
+class ProtocolError(PiranhasError):
+    """
+    Eine Nachricht des Spielservers ist kein gültiges XML oder beschreibt kein gültiges Spiel.
+    """
+
+class InvalidNotationError(PiranhasError):
+    """
+    Ein Text in Stellungs- oder Zugnotation kann nicht gelesen werden. Die Nachricht nennt die Spalte des Fehlers.
+    """
+
+class ScoreEntry:
+    """
+    Die Punkte eines Spielers im Ergebnis des Spielservers.
+
+    Attribute:
+        name (str): Der Name des Spielers.
+        team (TeamEnum): Das Team des Spielers.
+        parts (List[int]): Ein Wert pro Fragment der Ergebnisdefinition (*ServerResult.fragments*).
+    """
+
+    name: str
+    team: TeamEnum
+    parts: List[int]
+
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: ScoreEntry) -> bool: ...
+    def __ne__(self, other: ScoreEntry) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> ScoreEntry: ...
+    def __deepcopy__(self, memo: dict) -> ScoreEntry: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], ScoreEntry], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
+        """
+        ...
+
+    @staticmethod
+    def from_bytes(data: bytes) -> ScoreEntry:
+        """
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
+
+        Args:
+            data (bytes): Die binäre Kodierung.
+
+        Returns:
+            ScoreEntry: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
+
+class ServerResult:
+    """
+    Das Ergebnis eines Spiels, wie es der Spielserver am Ende sendet.<br>
+    Im Gegensatz zu *GameResult* wird es nicht aus dem Spielstand berechnet.
+
+    Attribute:
+        fragments (List[str]): Die Namen der Wertungen, z.B. "Siegpunkte".
+        scores (List[ScoreEntry]): Die Punkte beider Spieler.
+        winner (Optional[TeamEnum]): Das Team, das gewonnen hat, oder None bei einem Unentschieden.
+        regular (bool): Ob das Spiel regulär beendet wurde.
+        reason (str): Die Begründung des Servers.
+    """
+
+    fragments: List[str]
+    scores: List[ScoreEntry]
+    winner: Optional[TeamEnum]
+    regular: bool
+    reason: str
+
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: ServerResult) -> bool: ...
+    def __ne__(self, other: ServerResult) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> ServerResult: ...
+    def __deepcopy__(self, memo: dict) -> ServerResult: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], ServerResult], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
+        """
+        ...
+
+    @staticmethod
+    def from_bytes(data: bytes) -> ServerResult:
+        """
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
+
+        Args:
+            data (bytes): Die binäre Kodierung.
+
+        Returns:
+            ServerResult: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
+
+    def score_of(self, team: TeamEnum) -> Optional[ScoreEntry]:
+        """
+        Gibt die Punkte eines Teams zurück.
+
+        Args:
+            team (TeamEnum): Das Team.
+
+        Returns:
+            Optional[ScoreEntry]: Die Punkte, oder None, wenn das Team nicht im Ergebnis vorkommt.
+        """
+        ...
+
+class XmlCodec:
+    """
+    Liest und schreibt die XML-Nachrichten des Spielservers direkt in Rust.
+
+    Nachrichten mit nicht geschlossenen Elementen, z.B. abgeschnittene, werden mit einem ProtocolError abgelehnt.
+    Team One beginnt immer, das Team am Zug ergibt sich allein aus dem Zug. Spielstände mit `startTeam="TWO"`
+    werden daher abgelehnt und *state_to_xml* schreibt immer `startTeam="ONE"`.
+    """
+
+    @staticmethod
+    def parse_room(xml: str) -> Optional[Union[GameState, Move, ServerResult]]:
+        """
+        Liest eine `<room>`-Nachricht des Servers.
+
+        Args:
+            xml (str): Die Nachricht.
+
+        Returns:
+            Optional[Union[GameState, Move, ServerResult]]: Der Spielstand (`memento`), der Zug (`move`) oder das Ergebnis (`result`),
+                None für alle anderen Nachrichten.
+
+        Raises:
+            ProtocolError: Wenn die Nachricht nicht gelesen werden kann.
+        """
+        ...
+
+    @staticmethod
+    def room_id(xml: str) -> str:
+        """
+        Gibt die Raum-ID einer `<room>`-Nachricht zurück.
+
+        Raises:
+            ProtocolError: Wenn die Nachricht nicht gelesen werden kann.
+        """
+        ...
+
+    @staticmethod
+    def parse_state(xml: str) -> GameState:
+        """
+        Liest einen Spielstand, entweder aus einem `<state>`-Element oder aus einer `<room>`-Nachricht.
+
+        Raises:
+            ProtocolError: Wenn die Nachricht keinen gültigen Spielstand enthält.
+        """
+        ...
+
+    @staticmethod
+    def parse_move(xml: str) -> Move:
+        """
+        Liest einen Zug, entweder aus einem `<data class="move">`-Element oder aus einer `<room>`-Nachricht.
+
+        Raises:
+            ProtocolError: Wenn die Nachricht keinen gültigen Zug enthält.
+        """
+        ...
+
+    @staticmethod
+    def parse_result(xml: str) -> ServerResult:
+        """
+        Liest ein Ergebnis, entweder aus einem `<data class="result">`-Element oder aus einer `<room>`-Nachricht.
+
+        Raises:
+            ProtocolError: Wenn die Nachricht kein gültiges Ergebnis enthält.
+        """
+        ...
+
+    @staticmethod
+    def move_to_xml(move: Move, room_id: Optional[str] = None) -> str:
+        """
+        Schreibt einen Zug in der Form, in der er an den Server gesendet wird.
+
+        Args:
+            move (Move): Der Zug.
+            room_id (Optional[str]): Wenn angegeben, wird der Zug in eine `<room>`-Nachricht verpackt.
+
+        Returns:
+            str: Das XML, z.B. `<data class="move"><from x="0" y="3"/><direction>RIGHT</direction></data>`.
+        """
+        ...
+
+    @staticmethod
+    def state_to_xml(state: GameState) -> str:
+        """
+        Schreibt einen Spielstand als `<state>`-Element, so wie ihn der Server sendet.
+
+        Args:
+            state (GameState): Der Spielstand.
+
+        Returns:
+            str: Das XML.
+        """
+        ...
+
+class Replay:
+    """
+    Ein aufgezeichnetes Spiel als Folge von Spielständen, z.B. um verlorene Spiele Zug für Zug nachzuvollziehen.
+
+    Gelesen werden sowohl mitgeschnittene Protokolle (`<room>`-Nachrichten mit `memento` und `result`)
+    als auch Replay-Dateien des Servers (`<state>`-Elemente). Sendet der Server denselben Spielstand
+    an beide Spieler, wird er nur einmal übernommen.
+
+    Attribute:
+        states (List[GameState]): Ein Spielstand pro Zug in der gespielten Reihenfolge.
+        result (Optional[ServerResult]): Das Ergebnis, falls es aufgezeichnet wurde.
+    """
+
+    states: List[GameState]
+    result: Optional[ServerResult]
+
+    def __init__(self, states: List[GameState], result: Optional[ServerResult] = None) -> None: ...
+
+    @staticmethod
+    def from_xml(xml: str) -> Replay:
+        """
+        Liest ein Spiel aus XML.
+
+        Raises:
+            ProtocolError: Wenn das XML nicht gelesen werden kann, z.B. weil es aus einer anderen Saison stammt
+                oder abgeschnitten ist.
+        """
+        ...
+
+    @staticmethod
+    def from_file(path: str) -> Replay:
+        """
+        Liest ein Spiel aus einer XML-Datei.
+
+        Raises:
+            OSError: Wenn die Datei nicht gelesen werden kann.
+            ProtocolError: Wenn das XML nicht gelesen werden kann.
+        """
+        ...
+
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: Replay) -> bool: ...
+    def __ne__(self, other: Replay) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __len__(self) -> int: ...
+    def __copy__(self) -> Replay: ...
+    def __deepcopy__(self, memo: dict) -> Replay: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], Replay], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
+        """
+        ...
+
+    @staticmethod
+    def from_bytes(data: bytes) -> Replay:
+        """
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
+
+        Args:
+            data (bytes): Die binäre Kodierung.
+
+        Returns:
+            Replay: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
+
+    def __getitem__(self, index: int) -> GameState:
+        """
+        Gibt den Spielstand an einer Position zurück, negative Positionen zählen vom Ende.
+
+        Raises:
+            IndexError: Wenn die Position außerhalb des Spiels liegt.
+        """
+        ...
+    def __iter__(self) -> Iterator[GameState]: ...
+
+    def moves(self) -> List[Tuple[GameState, Optional[Move]]]:
+        """
+        Gibt jeden Spielstand mit dem Zug zurück, der zum nächsten Spielstand führt.
+
+        Returns:
+            List[Tuple[GameState, Optional[Move]]]: Ein Paar je Spielstand. Der Zug ist None für den letzten Spielstand
+            und wenn der nächste Spielstand nicht direkt folgt, z.B. nach einem ausgelassenen Zug oder einer Lücke im Log.
+        """
+        ...
+
+    def state_at(self, turn: int) -> Optional[GameState]:
+        """
+        Springt zu einem Zug.
+
+        Args:
+            turn (int): Die Nummer des Zuges (*GameState.turn*).
 
-        Raises:
-            PiranhasError: Wenn der Zug nicht valide ist.
+        Returns:
+            Optional[GameState]: Der Spielstand, oder None, wenn der Zug nicht aufgezeichnet ist.
         """
         ...
 
-class RulesEngine:
+    def final_state(self) -> Optional[GameState]:
+        """
+        Gibt den letzten aufgezeichneten Spielstand zurück.
+        """
+        ...
+
+class MatchResult:
     """
-    Stellt Methoden, die zur Überprüfung der Spielregeln dienen.
+    Das Ergebnis eines lokal gespielten Spiels.
+
+    Attribute:
+        result (GameResult): Das Ergebnis nach den offiziellen Regeln, bei Zeitüberschreitung oder ungültigem Zug gewinnt der Gegner.
+        moves (List[Optional[Move]]): Ein Eintrag pro Zug in der gespielten Reihenfolge, None wenn ein Team ohne mögliche Züge aussetzen musste.
+            Ausgesetzte Züge werden nachgespielt, indem *turn* um eins erhöht wird.
+        move_times_ms (List[int]): Die Bedenkzeit jedes Zuges in Millisekunden, 0 für ausgesetzte Züge.
+        final_state (GameState): Der letzte Spielstand.
     """
 
+    result: GameResult
+    moves: List[Optional[Move]]
+    move_times_ms: List[int]
+    final_state: GameState
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: MatchResult) -> bool: ...
+    def __ne__(self, other: MatchResult) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> MatchResult: ...
+    def __deepcopy__(self, memo: dict) -> MatchResult: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], MatchResult], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
+        """
+        ...
+
     @staticmethod
-    def move_distance(board: Board, move_: Move) -> int:
+    def from_bytes(data: bytes) -> MatchResult:
         """
-        Gibt die Länge / Anzahl der Felder von einem Zug auf dem Spielfeld zurück.
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
 
         Args:
-            board (Board): Das Spielfeld, auf dem die Länge berechnet werden soll.
-            move_ (Move): Der zuverwendene Zug.
+            data (bytes): Die binäre Kodierung.
 
         Returns:
-            int: Die Länge.
+            MatchResult: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
+
+class Match:
+    """
+    Spielt ein ganzes Spiel zwischen zwei Spielern ohne Spielserver, z.B. um Bots in CI zu testen.
+
+    Spieler sind entweder ein *Searcher*, ein *MonteCarloSearcher* oder ein Objekt mit `calculate_move()`
+    wie ein *IClientHandler*. Solche Objekte erhalten vor jedem ihrer Züge den Spielstand über `on_update(state)`.
+
+    Attribute:
+        start (GameState): Der Spielstand, mit dem jedes Spiel beginnt.
+        time_limit_ms (int): Die maximale Bedenkzeit pro Zug in Millisekunden.
+    """
+
+    start: GameState
+    time_limit_ms: int
+
+    def __init__(self, start: GameState, time_limit_ms: int = 2000) -> None: ...
+    def __repr__(self) -> str: ...
+    def __copy__(self) -> Match: ...
+    def __deepcopy__(self, memo: dict) -> Match: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], Match], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
         """
         ...
 
     @staticmethod
-    def target_position(board: Board, move_: Move) -> Coordinate:
+    def from_bytes(data: bytes) -> Match:
         """
-        Gibt die Koordinate zurück, auf der ein Fisch landen würde, wenn man den Zug ausführt.
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
 
-        Es wird nicht berücksichtigt, ob diese Koordinate im Spielfeld ist.
+        Args:
+            data (bytes): Die binäre Kodierung.
+
+        Returns:
+            Match: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
+
+    def play(self, player_one: object, player_two: object) -> MatchResult:
+        """
+        Spielt ein Spiel bis zum Ende nach den offiziellen Regeln und dem Rundenlimit.
+        Ein Team ohne mögliche Züge setzt aus.
+
+        *Searcher* und *MonteCarloSearcher* brechen ihre Suche selbst vor dem Zeitlimit ab. Schafft die Alpha-Beta-Suche
+        nicht einmal Tiefe 1, spielt sie den ersten möglichen Zug.
+        Bei anderen Spielern wird das Zeitlimit erst geprüft, wenn sie ihren Zug zurückgeben.
+        Ein Python-Spieler, der nie zurückkehrt, hält das Spiel daher an, statt es durch Zeitüberschreitung zu verlieren.
 
         Args:
-            board (Board): Das Spielfeld, auf dem der Zug berechnet werden soll.
-            move_ (Move): Der zuverwendene Zug.
+            player_one (object): Der Spieler von Team One.
+            player_two (object): Der Spieler von Team Two.
 
         Returns:
-            Coordinate: Die Koordinate.
+            MatchResult: Das Ergebnis mit allen Zügen.
+
+        Raises:
+            TypeError: Wenn ein Spieler weder eine Suche ist noch `calculate_move` hat.
+            Exception: Fehler aus `calculate_move` werden weitergegeben.
+        """
+        ...
+
+class EngineKind:
+    """
+    Die Art einer Engine in der *Arena*.
+    """
+
+    AlphaBeta: EngineKind
+    MonteCarlo: EngineKind
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: EngineKind) -> bool: ...
+    def __ne__(self, other: EngineKind) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> EngineKind: ...
+    def __deepcopy__(self, memo: dict) -> EngineKind: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], EngineKind], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
         """
         ...
 
     @staticmethod
-    def is_in_bounds(coordinate: Coordinate) -> bool:
+    def from_bytes(data: bytes) -> EngineKind:
         """
-        Gibt einen Wahrheitswert zurück, ob eine Position in dem (Standard-) Spielfeld (10x10) liegt.
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
 
         Args:
-            coordinate (Coordinate): Die Position
+            data (bytes): Die binäre Kodierung.
 
         Returns:
-            bool: Ob die Koordinate im Feld ist.
+            EngineKind: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
+
+class EngineConfig:
+    """
+    Beschreibt eine Engine, die die *Arena* für jedes Spiel neu erstellt.
+    Nur native Bewertungsfunktionen sind möglich, damit Spiele parallel laufen können.
+
+    Attribute:
+        kind (EngineKind): Alpha-Beta-Suche oder Monte-Carlo-Baumsuche.
+        max_depth (int): Die maximale Suchtiefe der Alpha-Beta-Suche.
+        exploration (float): Die Explorationskonstante der Monte-Carlo-Baumsuche.
+        playout (Playout): Die Playout-Strategie der Monte-Carlo-Baumsuche.
+        evaluator (Evaluator): Die Gewichte der Bewertungsfunktion.
+    """
+
+    kind: EngineKind
+    max_depth: int
+    exploration: float
+    playout: Playout
+    evaluator: Evaluator
+
+    def __init__(
+        self,
+        kind: EngineKind = EngineKind.AlphaBeta,
+        max_depth: int = 64,
+        exploration: float = 1.4142135623730951,
+        playout: Playout = Playout.Random,
+        evaluator: Optional[Evaluator] = None,
+    ) -> None: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: EngineConfig) -> bool: ...
+    def __ne__(self, other: EngineConfig) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> EngineConfig: ...
+    def __deepcopy__(self, memo: dict) -> EngineConfig: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], EngineConfig], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
         """
         ...
 
     @staticmethod
-    def can_execute_move(board: Board, move_: Move) -> None:
+    def from_bytes(data: bytes) -> EngineConfig:
         """
-        Prüft, ob ein Zug auf dem Board nach den Regeln durchgeführt werden könnte.<br>
-        Dabei ist nicht relevant, welcher Spieler gerade tatsächlich dran wäre.
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
 
-        Gibt keinen Wert zurück, sondern wirft eine Fehlermeldung, falls der Zug nicht valide ist.
+        Args:
+            data (bytes): Die binäre Kodierung.
+
+        Returns:
+            EngineConfig: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
+
+class Sprt:
+    """
+    Ein sequentieller Likelihood-Quotienten-Test, der die *Arena* vorzeitig beendet.
+
+    Attribute:
+        elo0 (float): Der Elo-Unterschied der Nullhypothese (keine Verbesserung).
+        elo1 (float): Der Elo-Unterschied der Alternativhypothese (Verbesserung).
+        alpha (float): Die Wahrscheinlichkeit, H1 fälschlich anzunehmen.
+        beta (float): Die Wahrscheinlichkeit, H0 fälschlich anzunehmen.
+    """
+
+    elo0: float
+    elo1: float
+    alpha: float
+    beta: float
+
+    def __init__(self, elo0: float = 0.0, elo1: float = 5.0, alpha: float = 0.05, beta: float = 0.05) -> None: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: Sprt) -> bool: ...
+    def __ne__(self, other: Sprt) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> Sprt: ...
+    def __deepcopy__(self, memo: dict) -> Sprt: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], Sprt], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
+        """
+        ...
+
+    @staticmethod
+    def from_bytes(data: bytes) -> Sprt:
+        """
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
 
         Args:
-            board (Board): Das Spielfeld.
-            move_ (Move): Der Zug, der geprüft werden soll.
+            data (bytes): Die binäre Kodierung.
+
+        Returns:
+            Sprt: Das gelesene Objekt.
 
         Raises:
-            PiranhasError: Wenn der Zug nicht valide ist.
+            ValueError: Wenn die Daten ungültig sind.
         """
         ...
 
-    @staticmethod 
-    def get_team_on_turn(turn: int) -> TeamEnum:
+    def bounds(self) -> Tuple[float, float]:
         """
-        Berechnet anhand der Zugzahl, welcher Spieler dran sein müsste.<br>
-        Es wird nicht beachtet, ob die Zahl kleiner 0 oder größer 59 ist.
+        Returns:
+            Tuple[float, float]: Die untere und obere Schranke für die LLR.
+        """
+        ...
+
+    def llr(self, wins: int, draws: int, losses: int) -> float:
+        """
+        Berechnet die Log-Likelihood-Ratio mit der Normalverteilungsnäherung.
 
         Args:
-            turn (int): Die Zugzahl.
+            wins (int): Die Anzahl der Siege.
+            draws (int): Die Anzahl der Unentschieden.
+            losses (int): Die Anzahl der Niederlagen.
 
         Returns:
-            TeamEnum: Das Team, was dran ist.
+            float: Die LLR, positiv spricht für H1.
+        """
+        ...
+
+    def decide(self, wins: int, draws: int, losses: int) -> Optional[bool]:
+        """
+        Args:
+            wins (int): Die Anzahl der Siege.
+            draws (int): Die Anzahl der Unentschieden.
+            losses (int): Die Anzahl der Niederlagen.
+
+        Returns:
+            Optional[bool]: True wenn H1 angenommen wird, False wenn H0 angenommen wird, sonst None.
+        """
+        ...
+
+class ArenaResult:
+    """
+    Das Ergebnis einer *Arena* aus Sicht der ersten Engine.
+
+    Attribute:
+        wins (int): Die Anzahl der Siege.
+        draws (int): Die Anzahl der Unentschieden.
+        losses (int): Die Anzahl der Niederlagen.
+        llr (Optional[float]): Die Log-Likelihood-Ratio, wenn ein SPRT lief.
+        sprt_accepted (Optional[bool]): True wenn H1, False wenn H0 angenommen wurde, sonst None.
+    """
+
+    wins: int
+    draws: int
+    losses: int
+    llr: Optional[float]
+    sprt_accepted: Optional[bool]
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: ArenaResult) -> bool: ...
+    def __ne__(self, other: ArenaResult) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> ArenaResult: ...
+    def __deepcopy__(self, memo: dict) -> ArenaResult: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], ArenaResult], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
         """
         ...
 
     @staticmethod
-    def swarm_from(board: Board, position: Coordinate) -> List[Coordinate]:
+    def from_bytes(data: bytes) -> ArenaResult:
         """
-        Berechnet auf einem Spielbrett von einer Startposition aus, welche Fische
-        von dort aus in einem Schwarm zusammenhängen.
-        Dabei werden nur Fische beachtet, die im selben Team sind, wie der auf dem Startfeld.
-        Gibt eine Liste an Koordinaten zurück, die leer ist, wenn die Startposition
-        außerhalb des Spielbrettes ist, oder kein Fisch als Start angegeben ist.
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
 
         Args:
-            board (Board): Das Spielbrett.
-            position (Coordinate): Die Startkoordinate
+            data (bytes): Die binäre Kodierung.
 
         Returns:
-            List[Coordinate]: Die Liste an zusammenhängenden Fischen.
+            ArenaResult: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
+
+    def games(self) -> int:
+        """
+        Returns:
+            int: Die Anzahl der gespielten Spiele.
+        """
+        ...
+
+    def score(self) -> float:
+        """
+        Returns:
+            float: Der Punkteanteil, Unentschieden zählen halb.
+        """
+        ...
+
+    def elo(self) -> float:
+        """
+        Returns:
+            float: Der geschätzte Elo-Unterschied.
+        """
+        ...
+
+    def elo_error(self) -> float:
+        """
+        Returns:
+            float: Die halbe Breite des 95%-Konfidenzintervalls der Elo-Schätzung.
+        """
+        ...
+
+class Arena:
+    """
+    Lässt zwei Engines parallel viele Spiele von zufälligen Startaufstellungen gegeneinander spielen.
+    Jede Startaufstellung wird zweimal mit getauschten Farben gespielt.
+    Auch als Programm `arena` aus Rust nutzbar.
+
+    Attribute:
+        games (int): Die maximale Anzahl der Spiele.
+        threads (int): Die Anzahl paralleler Spiele. Wird nicht mit pickle gespeichert,
+            beim Laden gilt der Standardwert des jeweiligen Rechners.
+        time_limit_ms (int): Die maximale Bedenkzeit pro Zug in Millisekunden.
+        seed (int): Der Seed der ersten Startaufstellung.
+        sprt (Optional[Sprt]): Ein optionaler Test, der die Arena vorzeitig beendet.
+            Spiele, die nach seiner Entscheidung noch enden, werden nicht mehr gezählt.
+    """
 
+    games: int
+    threads: int
+    time_limit_ms: int
+    seed: int
+    sprt: Optional[Sprt]
+
+    def __init__(
+        self,
+        games: int = 100,
+        threads: Optional[int] = None,
+        time_limit_ms: int = 2000,
+        seed: int = 0,
+        sprt: Optional[Sprt] = None,
+    ) -> None: ...
+    def __repr__(self) -> str: ...
+    def __copy__(self) -> Arena: ...
+    def __deepcopy__(self, memo: dict) -> Arena: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], Arena], Tuple[bytes]]: ...
+
+    def to_bytes(self) -> bytes:
+        """
+        Kodiert das Objekt kompakt binär, z.B. für pickle oder als Trainingsdaten.
+
+        Returns:
+            bytes: Die binäre Kodierung.
         """
         ...
 
     @staticmethod
-    def swarms_of_team(board: Board, team: TeamEnum) -> List[List[Coordinate]]:
+    def from_bytes(data: bytes) -> Arena:
         """
-        Berechnet auf einem Spielbrett alle Schwärme, die ein Team gerade gebildet hat.
-        Gibt eine 2-Dimensionale Liste zurück, wobei jede Sub-Liste ein einzelner Schwarm ist.
+        Liest ein Objekt aus der Kodierung von *to_bytes*.
 
         Args:
-            board (Board): Das Spielbrett.
-            team (TeamEnum): Das gewählte Team.
+            data (bytes): Die binäre Kodierung.
 
         Returns:
-            List[List[Coordinate]]: Die Liste an Schwärmen.
+            Arena: Das gelesene Objekt.
+
+        Raises:
+            ValueError: Wenn die Daten ungültig sind.
+        """
+        ...
+
+    def run(self, first: EngineConfig, second: EngineConfig) -> ArenaResult:
+        """
+        Spielt alle Spiele, ohne die GIL zu halten.
+
+        Args:
+            first (EngineConfig): Die erste Engine, aus deren Sicht gezählt wird.
+            second (EngineConfig): Die zweite Engine.
+
+        Returns:
+            ArenaResult: Siege, Unentschieden und Niederlagen mit Elo-Schätzung.
+        """
+        ...
+
+class Symmetry(Enum):
+    """
+    Die 8 Symmetrien des quadratischen Spielbretts. Drehungen sind gegen den Uhrzeigersinn.
+    """
+
+    Identity: int = 0
+    Rotate90: int = 1
+    Rotate180: int = 2
+    Rotate270: int = 3
+    FlipHorizontal: int = 4
+    """
+    Spiegelt x, tauscht also links und rechts.
+    """
+    FlipVertical: int = 5
+    """
+    Spiegelt y, tauscht also unten und oben.
+    """
+    Transpose: int = 6
+    """
+    Spiegelt an der Diagonalen von unten links nach oben rechts.
+    """
+    AntiTranspose: int = 7
+    """
+    Spiegelt an der Diagonalen von oben links nach unten rechts.
+    """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: Symmetry) -> bool: ...
+    def __ne__(self, other: Symmetry) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> Symmetry: ...
+    def __deepcopy__(self, memo: dict) -> Symmetry: ...
+    def __reduce__(self) -> Tuple[Callable[[bytes], Symmetry], Tuple[bytes]]: ...
+    def to_bytes(self) -> bytes: ...
+    @staticmethod
+    def from_bytes(data: bytes) -> Symmetry: ...
+
+    @staticmethod
+    def all_symmetries() -> List[Symmetry]:
+        """
+        Returns:
+            List[Symmetry]: Alle 8 Symmetrien.
+        """
+        ...
+
+    def swaps_teams(self) -> bool:
+        """
+        Team One startet in den Spalten und Team Two in den Zeilen, Symmetrien, die die Achsen tauschen, tauschen daher die Teams.
+
+        Returns:
+            bool: Ob die Symmetrie die Teams tauscht.
+        """
+        ...
 
+    def inverse(self) -> Symmetry:
+        """
+        Returns:
+            Symmetry: Die Symmetrie, die diese rückgängig macht.
         """
         ...
 
+    def transform_vector(self, vector: Vector) -> Vector: ...
+    def transform_coordinate(self, position: Coordinate) -> Coordinate: ...
+    def transform_field(self, field: FieldType) -> FieldType: ...
+
 class PluginConstants:
     """
     Hält globale Konstanten.
@@ -836,3 +3235,11 @@ class PluginConstants:
     BOARD_HEIGHT: int
 
     ROUND_LIMIT: int
+
+    SMALL_FISH: int
+    MEDIUM_FISH: int
+    LARGE_FISH: int
+
+    BOARD_PLANES: int
+    STATE_PLANES: int
+    MOVE_INDICES: int
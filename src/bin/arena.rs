@@ -0,0 +1,66 @@
+// runs an arena between two engine configurations from the command line
+//
+// usage: arena [--games N] [--threads N] [--time-ms N] [--seed N] [--sprt ELO0,ELO1] [ENGINE_A] [ENGINE_B]
+// engines are given as "alphabeta", "alphabeta:DEPTH", "mcts" or "mcts-heuristic",
+// the monte carlo searches have no depth and run until their time is up
+
+use std::process::exit;
+
+use socha::plugin2026::{
+    arena::{Arena, EngineConfig, EngineKind, Sprt},
+    r#match::Match,
+    search::mcts::Playout
+};
+
+fn parse_engine(text: &str) -> Option<EngineConfig> {
+    let (kind, depth) = match text.split_once(':') {
+        Some((kind, depth)) => (kind, Some(depth.parse().ok()?)),
+        None => (text, None),
+    };
+
+    match (kind, depth) {
+        ("alphabeta", depth) => Some(EngineConfig::new(EngineKind::AlphaBeta, depth.unwrap_or(64), std::f64::consts::SQRT_2, Playout::Random, None)),
+        ("mcts", None) => Some(EngineConfig::new(EngineKind::MonteCarlo, 0, std::f64::consts::SQRT_2, Playout::Random, None)),
+        ("mcts-heuristic", None) => Some(EngineConfig::new(EngineKind::MonteCarlo, 0, std::f64::consts::SQRT_2, Playout::Heuristic, None)),
+        _ => None,
+    }
+}
+
+fn parse_sprt(text: &str) -> Option<Sprt> {
+    let (elo0, elo1) = text.split_once(',')?;
+    Some(Sprt::new(elo0.parse().ok()?, elo1.parse().ok()?, 0.05, 0.05))
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    eprintln!("usage: arena [--games N] [--threads N] [--time-ms N] [--seed N] [--sprt ELO0,ELO1] [ENGINE_A] [ENGINE_B]");
+    exit(2);
+}
+
+fn main() {
+    let mut arena = Arena::new(100, None, Match::DEFAULT_TIME_LIMIT_MS, 0, None);
+    let mut engines: Vec<EngineConfig> = vec![];
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().unwrap_or_else(|| fail(&format!("Missing value for {}", arg)));
+
+        match arg.as_str() {
+            "--games" => arena.games = value().parse().unwrap_or_else(|_| fail("Invalid number of games")),
+            "--threads" => arena.threads = value().parse().unwrap_or_else(|_| fail("Invalid number of threads")),
+            "--time-ms" => arena.time_limit_ms = value().parse().unwrap_or_else(|_| fail("Invalid time limit")),
+            "--seed" => arena.seed = value().parse().unwrap_or_else(|_| fail("Invalid seed")),
+            "--sprt" => arena.sprt = Some(parse_sprt(&value()).unwrap_or_else(|| fail("Invalid SPRT bounds"))),
+            engine => engines.push(parse_engine(engine).unwrap_or_else(|| fail(&format!("Unknown engine {}, a depth is only allowed for alphabeta", engine)))),
+        }
+    }
+
+    let default = || parse_engine("alphabeta").expect("Default engine is valid");
+    let second = engines.pop().unwrap_or_else(default);
+    let first = engines.pop().unwrap_or_else(default);
+    if !engines.is_empty() {
+        fail("At most two engines can be given");
+    }
+
+    println!("{}", arena.run(&first, &second));
+}
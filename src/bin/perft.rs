@@ -0,0 +1,55 @@
+// prints perft node counts and speed for the test board and random start positions
+//
+// usage: perft [--depth N] [--starts N] [--seed N]
+
+use std::{process::exit, time::Instant};
+
+use socha::plugin2026::{board::Board, game_state::GameState};
+
+// the board of the unit tests, see test::common::create_test_board
+const TEST_POSITION: &str = "1msllmmss1/S8S/L2*5S/S8M/M8M/S8L/L8L/S5*2S/M8M/1mslsmsls1 0 -";
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    eprintln!("usage: perft [--depth N] [--starts N] [--seed N]");
+    exit(2);
+}
+
+fn run(name: &str, state: &GameState, max_depth: usize) {
+    println!("{}", name);
+
+    for depth in 1..=max_depth {
+        let started = Instant::now();
+        let nodes = state.perft(depth);
+        let seconds = started.elapsed().as_secs_f64();
+
+        println!("  depth {:>2}: {:>14} nodes {:>10.3} s {:>14.0} nodes/s", depth, nodes, seconds, nodes as f64 / seconds.max(f64::EPSILON));
+    }
+}
+
+fn main() {
+    let mut depth = 4;
+    let mut starts = 3;
+    let mut seed = 0;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let value = args.next().unwrap_or_else(|| fail(&format!("Missing value for {}", arg)));
+
+        match arg.as_str() {
+            "--depth" => depth = value.parse().unwrap_or_else(|_| fail("Invalid depth")),
+            "--starts" => starts = value.parse().unwrap_or_else(|_| fail("Invalid number of starts")),
+            "--seed" => seed = value.parse().unwrap_or_else(|_| fail("Invalid seed")),
+            _ => fail(&format!("Unknown argument {}", arg)),
+        }
+    }
+
+    let test_state = GameState::from_notation(TEST_POSITION).expect("Test position is valid notation");
+    run("test board", &test_state, depth);
+
+    for i in 0..starts {
+        let start_seed: u64 = seed + i;
+        let state = GameState::new(Board::random_start(Some(start_seed)), 0, None);
+        run(&format!("random start {}", start_seed), &state, depth);
+    }
+}
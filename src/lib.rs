@@ -8,13 +8,29 @@ use crate::plugin2026::utils::direction::Direction;
 use crate::plugin2026::utils::coordinate::Coordinate;
 use crate::plugin2026::utils::constants::PluginConstants;
 use crate::plugin2026::utils::team::TeamEnum;
+use crate::plugin2026::utils::symmetry::Symmetry;
 
-use crate::plugin2026::game_state::GameState;
+use crate::plugin2026::game_state::{GameState, UndoToken};
+use crate::plugin2026::game_result::{GameResult, WinReason};
 use crate::plugin2026::board::Board;
+use crate::plugin2026::swarm::Swarm;
 use crate::plugin2026::field_type::FieldType;
 use crate::plugin2026::r#move::Move;
 
 use crate::plugin2026::rules_engine::RulesEngine;
+use crate::plugin2026::errors::{
+    BlockedBySquidError, GameOverError, InvalidNotationError, JumpsOverOpponentError, NotAFishError, OwnFishOnTargetError,
+    PiranhasError, ProtocolError, StaleLastMoveError, StaleUndoTokenError, StartOutOfBoundsError, TargetOutOfBoundsError,
+    WrongTeamError
+};
+use crate::plugin2026::search::evaluator::Evaluator;
+use crate::plugin2026::search::mcts::{MonteCarloSearcher, Playout};
+use crate::plugin2026::search::searcher::Searcher;
+use crate::plugin2026::search::transposition_table::{BoundType, TableEntry, TranspositionTable};
+use crate::plugin2026::xml_codec::{ScoreEntry, ServerResult, XmlCodec};
+use crate::plugin2026::replay::{Replay, ReplayIterator};
+use crate::plugin2026::r#match::{Match, MatchResult};
+use crate::plugin2026::arena::{Arena, ArenaResult, EngineConfig, EngineKind, Sprt};
 
 #[pymodule]
 fn _socha(m: &Bound<'_, PyModule>) -> PyResult<()> {
@@ -25,13 +41,52 @@ fn _socha(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Coordinate>()?;
     m.add_class::<PluginConstants>()?;
     m.add_class::<TeamEnum>()?;
+    m.add_class::<Symmetry>()?;
 
     m.add_class::<GameState>()?;
+    m.add_class::<UndoToken>()?;
+    m.add_class::<GameResult>()?;
+    m.add_class::<WinReason>()?;
     m.add_class::<Board>()?;
+    m.add_class::<Swarm>()?;
     m.add_class::<FieldType>()?;
     m.add_class::<Move>()?;
 
     m.add_class::<RulesEngine>()?;
+    m.add_class::<Evaluator>()?;
+    m.add_class::<Searcher>()?;
+    m.add_class::<MonteCarloSearcher>()?;
+    m.add_class::<Playout>()?;
+    m.add_class::<TranspositionTable>()?;
+    m.add_class::<TableEntry>()?;
+    m.add_class::<BoundType>()?;
+
+    m.add_class::<XmlCodec>()?;
+    m.add_class::<ServerResult>()?;
+    m.add_class::<ScoreEntry>()?;
+    m.add_class::<Replay>()?;
+    m.add_class::<ReplayIterator>()?;
+    m.add_class::<Match>()?;
+    m.add_class::<MatchResult>()?;
+    m.add_class::<Arena>()?;
+    m.add_class::<ArenaResult>()?;
+    m.add_class::<EngineConfig>()?;
+    m.add_class::<EngineKind>()?;
+    m.add_class::<Sprt>()?;
+
+    m.add("PiranhasError", m.py().get_type_bound::<PiranhasError>())?;
+    m.add("StartOutOfBoundsError", m.py().get_type_bound::<StartOutOfBoundsError>())?;
+    m.add("TargetOutOfBoundsError", m.py().get_type_bound::<TargetOutOfBoundsError>())?;
+    m.add("NotAFishError", m.py().get_type_bound::<NotAFishError>())?;
+    m.add("WrongTeamError", m.py().get_type_bound::<WrongTeamError>())?;
+    m.add("BlockedBySquidError", m.py().get_type_bound::<BlockedBySquidError>())?;
+    m.add("OwnFishOnTargetError", m.py().get_type_bound::<OwnFishOnTargetError>())?;
+    m.add("JumpsOverOpponentError", m.py().get_type_bound::<JumpsOverOpponentError>())?;
+    m.add("GameOverError", m.py().get_type_bound::<GameOverError>())?;
+    m.add("StaleLastMoveError", m.py().get_type_bound::<StaleLastMoveError>())?;
+    m.add("StaleUndoTokenError", m.py().get_type_bound::<StaleUndoTokenError>())?;
+    m.add("ProtocolError", m.py().get_type_bound::<ProtocolError>())?;
+    m.add("InvalidNotationError", m.py().get_type_bound::<InvalidNotationError>())?;
 
     Ok(())
 }
@@ -1,8 +1,16 @@
 pub mod rules_engine;
 pub mod game_state;
+pub mod game_result;
 pub mod field_type;
 pub mod board;
+pub mod swarm;
 pub mod r#move;
 pub mod utils;
+pub mod search;
 pub mod test;
-pub mod errors;
\ No newline at end of file
+pub mod errors;
+pub mod xml_codec;
+pub mod replay;
+pub mod r#match;
+pub mod arena;
+pub mod notation;
\ No newline at end of file
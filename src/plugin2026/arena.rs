@@ -0,0 +1,416 @@
+use std::{
+    borrow::Cow,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Mutex
+    }
+};
+
+use pyo3::*;
+
+use crate::plugin2026::{
+    board::Board,
+    game_state::GameState,
+    r#match::{Match, Player},
+    search::{
+        evaluator::{EvaluationFunction, Evaluator},
+        mcts::{MonteCarloSearcher, Playout},
+        searcher::Searcher
+    },
+    utils::{binary, hashing, random::Random, team::TeamEnum}
+};
+
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EngineKind {
+    AlphaBeta,
+    MonteCarlo
+}
+
+#[pymethods]
+impl EngineKind {
+    fn __str__(&self) -> String {format!("{:?}", self)}
+    fn __repr__(&self) -> String {format!("{:?}", self)}
+    fn __eq__(&self, other: &EngineKind) -> bool {self == other}
+    fn __ne__(&self, other: &EngineKind) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __copy__(&self) -> EngineKind {*self}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> EngineKind {*self}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<EngineKind> {
+        binary::from_bytes(data)
+    }
+}
+
+// everything needed to build a fresh engine for every game, only native evaluators can run in parallel
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EngineConfig {
+    #[pyo3(get, set)]
+    pub kind: EngineKind,
+    #[pyo3(get, set)]
+    pub max_depth: usize,
+    #[pyo3(get, set)]
+    pub exploration: f64,
+    #[pyo3(get, set)]
+    pub playout: Playout,
+    #[pyo3(get, set)]
+    pub evaluator: Evaluator,
+}
+
+#[pymethods]
+impl EngineConfig {
+    #[new]
+    #[pyo3(signature = (kind=EngineKind::AlphaBeta, max_depth=64, exploration=std::f64::consts::SQRT_2, playout=Playout::Random, evaluator=None))]
+    pub fn new(kind: EngineKind, max_depth: usize, exploration: f64, playout: Playout, evaluator: Option<Evaluator>) -> Self {
+        Self {
+            kind,
+            max_depth,
+            exploration,
+            playout,
+            evaluator: evaluator.unwrap_or_default(),
+        }
+    }
+
+    fn __repr__(&self) -> String {format!("{:?}", self)}
+    fn __eq__(&self, other: &EngineConfig) -> bool {self == other}
+    fn __ne__(&self, other: &EngineConfig) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __copy__(&self) -> EngineConfig {self.clone()}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> EngineConfig {self.clone()}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<EngineConfig> {
+        binary::from_bytes(data)
+    }
+}
+
+impl std::hash::Hash for EngineConfig {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        (self.kind, self.max_depth, hashing::float_bits(self.exploration), self.playout, &self.evaluator).hash(state);
+    }
+}
+
+// rust exclusive methods
+impl EngineConfig {
+    pub fn build(&self, seed: u64) -> Box<dyn Player + Send> {
+        let evaluation = EvaluationFunction::Native(self.evaluator.clone());
+
+        match self.kind {
+            EngineKind::AlphaBeta => Box::new(Searcher::new(self.max_depth, evaluation)),
+            EngineKind::MonteCarlo => Box::new(MonteCarloSearcher::new(self.exploration, self.playout, Random::new(seed), evaluation)),
+        }
+    }
+}
+
+// sequential probability ratio test between elo0 (no improvement) and elo1 (improvement)
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sprt {
+    #[pyo3(get, set)]
+    pub elo0: f64,
+    #[pyo3(get, set)]
+    pub elo1: f64,
+    #[pyo3(get, set)]
+    pub alpha: f64,
+    #[pyo3(get, set)]
+    pub beta: f64,
+}
+
+#[pymethods]
+impl Sprt {
+    #[new]
+    #[pyo3(signature = (elo0=0.0, elo1=5.0, alpha=0.05, beta=0.05))]
+    pub fn new(elo0: f64, elo1: f64, alpha: f64, beta: f64) -> Self {
+        Self { elo0, elo1, alpha, beta }
+    }
+
+    fn __repr__(&self) -> String {format!("{:?}", self)}
+    fn __eq__(&self, other: &Sprt) -> bool {self == other}
+    fn __ne__(&self, other: &Sprt) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __copy__(&self) -> Sprt {self.clone()}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Sprt {self.clone()}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Sprt> {
+        binary::from_bytes(data)
+    }
+
+    // the log likelihood ratio has to leave these bounds to decide
+    pub fn bounds(&self) -> (f64, f64) {
+        ((self.beta / (1.0 - self.alpha)).ln(), ((1.0 - self.beta) / self.alpha).ln())
+    }
+
+    // normal approximation of the trinomial model, as used by most engine testing frameworks
+    pub fn llr(&self, wins: usize, draws: usize, losses: usize) -> f64 {
+        let games = (wins + draws + losses) as f64;
+        let variance = variance(wins, draws, losses);
+        if games == 0.0 || variance == 0.0 {
+            return 0.0;
+        }
+
+        let score = score(wins, draws, losses);
+        let (score0, score1) = (score_from_elo(self.elo0), score_from_elo(self.elo1));
+
+        (score1 - score0) * (2.0 * score - score0 - score1) * games / (2.0 * variance)
+    }
+
+    // true if elo1 is accepted, false if elo0 is accepted, None if more games are needed
+    pub fn decide(&self, wins: usize, draws: usize, losses: usize) -> Option<bool> {
+        let (lower, upper) = self.bounds();
+        let llr = self.llr(wins, draws, losses);
+
+        if llr >= upper {
+            Some(true)
+        } else if llr <= lower {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+impl std::hash::Hash for Sprt {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        [self.elo0, self.elo1, self.alpha, self.beta].map(hashing::float_bits).hash(state);
+    }
+}
+
+// all numbers are seen from the first engine
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ArenaResult {
+    #[pyo3(get)]
+    pub wins: usize,
+    #[pyo3(get)]
+    pub draws: usize,
+    #[pyo3(get)]
+    pub losses: usize,
+    #[pyo3(get)]
+    pub llr: Option<f64>,
+    #[pyo3(get)]
+    pub sprt_accepted: Option<bool>,
+}
+
+#[pymethods]
+impl ArenaResult {
+    fn __str__(&self) -> String {self.to_string()}
+    fn __repr__(&self) -> String {format!("{:?}", self)}
+    fn __eq__(&self, other: &ArenaResult) -> bool {self == other}
+    fn __ne__(&self, other: &ArenaResult) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __copy__(&self) -> ArenaResult {self.clone()}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> ArenaResult {self.clone()}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<ArenaResult> {
+        binary::from_bytes(data)
+    }
+
+    pub fn games(&self) -> usize {
+        self.wins + self.draws + self.losses
+    }
+
+    pub fn score(&self) -> f64 {
+        score(self.wins, self.draws, self.losses)
+    }
+
+    pub fn elo(&self) -> f64 {
+        elo_from_score(self.score())
+    }
+
+    // half the width of the 95% confidence interval, the score deviation is scaled by the slope of the elo curve
+    pub fn elo_error(&self) -> f64 {
+        let games = self.games() as f64;
+        let score = self.score();
+        if games < 2.0 || score <= 0.0 || score >= 1.0 {
+            return f64::INFINITY;
+        }
+
+        let deviation = 1.96 * (variance(self.wins, self.draws, self.losses) / games).sqrt();
+        deviation * 400.0 / (std::f64::consts::LN_10 * score * (1.0 - score))
+    }
+}
+
+impl std::hash::Hash for ArenaResult {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        (self.wins, self.draws, self.losses, self.llr.map(hashing::float_bits), self.sprt_accepted).hash(state);
+    }
+}
+
+impl std::fmt::Display for ArenaResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Spiele: {}, Siege: {}, Unentschieden: {}, Niederlagen: {}, Elo: {:.1} +/- {:.1}",
+            self.games(), self.wins, self.draws, self.losses, self.elo(), self.elo_error()
+        )?;
+
+        if let Some(llr) = self.llr {
+            write!(f, ", LLR: {:.2}", llr)?;
+        }
+        match self.sprt_accepted {
+            Some(true) => write!(f, " (H1 angenommen)"),
+            Some(false) => write!(f, " (H0 angenommen)"),
+            None => Ok(()),
+        }
+    }
+}
+
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone)]
+pub struct Arena {
+    #[pyo3(get, set)]
+    pub games: usize,
+    #[pyo3(get, set)]
+    pub threads: usize,
+    #[pyo3(get, set)]
+    pub time_limit_ms: u64,
+    #[pyo3(get, set)]
+    pub seed: u64,
+    #[pyo3(get, set)]
+    pub sprt: Option<Sprt>,
+}
+
+#[pymethods]
+impl Arena {
+    #[new]
+    #[pyo3(signature = (games=100, threads=None, time_limit_ms=Match::DEFAULT_TIME_LIMIT_MS, seed=0, sprt=None))]
+    pub fn new(games: usize, threads: Option<usize>, time_limit_ms: u64, seed: u64, sprt: Option<Sprt>) -> Self {
+        let threads = threads.unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |n| n.get()));
+        Self { games, threads, time_limit_ms, seed, sprt }
+    }
+
+    fn __repr__(&self) -> String {format!("{:?}", self)}
+    fn __copy__(&self) -> Arena {self.clone()}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Arena {self.clone()}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Arena> {
+        binary::from_bytes(data)
+    }
+
+    #[pyo3(name = "run")]
+    fn py_run(&self, py: Python<'_>, first: EngineConfig, second: EngineConfig) -> ArenaResult {
+        py.allow_threads(|| self.run(&first, &second))
+    }
+}
+
+// rust exclusive methods
+impl Arena {
+    pub fn run(&self, first: &EngineConfig, second: &EngineConfig) -> ArenaResult {
+        let next_game = AtomicUsize::new(0);
+        let stop = AtomicBool::new(false);
+        let result = Mutex::new(ArenaResult::default());
+
+        std::thread::scope(|scope| {
+            for _ in 0..self.threads.max(1) {
+                scope.spawn(|| loop {
+                    let game = next_game.fetch_add(1, Ordering::Relaxed);
+                    if game >= self.games || stop.load(Ordering::Relaxed) {
+                        break;
+                    }
+
+                    let winner = self.play_game(first, second, game);
+
+                    let mut result = result.lock().expect("No thread panics while holding the lock");
+
+                    // games that were still running when the test decided do not count anymore
+                    if result.sprt_accepted.is_some() {
+                        break;
+                    }
+
+                    match winner {
+                        Some(true) => result.wins += 1,
+                        Some(false) => result.losses += 1,
+                        None => result.draws += 1,
+                    }
+
+                    if let Some(sprt) = &self.sprt {
+                        result.llr = Some(sprt.llr(result.wins, result.draws, result.losses));
+                        result.sprt_accepted = sprt.decide(result.wins, result.draws, result.losses);
+                        if result.sprt_accepted.is_some() {
+                            stop.store(true, Ordering::Relaxed);
+                        }
+                    }
+                });
+            }
+        });
+
+        result.into_inner().expect("No thread panics while holding the lock")
+    }
+
+    // both engines play each start position once with each colour, Some(true) if the first engine won
+    fn play_game(&self, first: &EngineConfig, second: &EngineConfig, game: usize) -> Option<bool> {
+        let seed = self.seed.wrapping_add((game / 2) as u64);
+        let first_team = if game.is_multiple_of(2) { TeamEnum::One } else { TeamEnum::Two };
+
+        let mut first_engine = first.build(seed);
+        let mut second_engine = second.build(seed);
+        let (one, two) = match first_team {
+            TeamEnum::One => (&mut first_engine, &mut second_engine),
+            TeamEnum::Two => (&mut second_engine, &mut first_engine),
+        };
+
+        let start = GameState::new(Board::random_start(Some(seed)), 0, None);
+        let played = Match::new(start, self.time_limit_ms)
+            .play(one.as_mut(), two.as_mut())
+            .expect("Native engines do not raise errors");
+
+        played.result.winner.map(|winner| winner == first_team)
+    }
+}
+
+fn score(wins: usize, draws: usize, losses: usize) -> f64 {
+    let games = (wins + draws + losses) as f64;
+    if games == 0.0 {
+        return 0.5;
+    }
+    (wins as f64 + draws as f64 / 2.0) / games
+}
+
+// variance of the result of a single game
+fn variance(wins: usize, draws: usize, losses: usize) -> f64 {
+    let games = (wins + draws + losses) as f64;
+    if games == 0.0 {
+        return 0.0;
+    }
+
+    let score = score(wins, draws, losses);
+    (wins as f64 * (1.0 - score).powi(2) + draws as f64 * (0.5 - score).powi(2) + losses as f64 * score.powi(2)) / games
+}
+
+fn score_from_elo(elo: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf(-elo / 400.0))
+}
+
+fn elo_from_score(score: f64) -> f64 {
+    -400.0 * (1.0 / score - 1.0).log10()
+}
@@ -1,117 +1,281 @@
-use pyo3::*;
+use std::borrow::Cow;
+
+use pyo3::{exceptions::PyValueError, *};
 
 use crate::plugin2026::{
     field_type::FieldType,
     utils::{
+        binary,
+        bitboard::{self, Bitboard},
         constants::PluginConstants,
-        coordinate::Coordinate, 
-        direction::Direction
+        coordinate::Coordinate,
+        direction::Direction,
+        hashing,
+        random::Random,
+        symmetry::Symmetry,
+        team::TeamEnum,
+        tensor,
+        zobrist
     }
 };
 
-#[pyclass]
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[pyclass(module = "socha._socha")]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Vec<Vec<FieldType>>", into = "Vec<Vec<FieldType>>"))]
 pub struct Board {
-    #[pyo3(get, set)]
-    pub map: Vec<Vec<FieldType>>,
+    // indexed by team and fish size, see Board::slot_of
+    fish: [[Bitboard; 3]; 2],
+    squids: Bitboard,
+    // kept up to date by every change in Board::set_field
+    zobrist: u64,
 }
 
 #[pymethods]
 impl Board {
     #[new]
-    pub fn new(map: Vec<Vec<FieldType>>) -> Self {
-        Self { map }
+    pub fn new(map: Vec<Vec<FieldType>>) -> PyResult<Self> {
+        Board::try_from(map).map_err(PyValueError::new_err)
+    }
+
+    // team one starts on the left and right column, team two on the bottom and top row, mirrored along the diagonal
+    #[staticmethod]
+    #[pyo3(signature = (seed=None))]
+    pub fn random_start(seed: Option<u64>) -> Board {
+        let mut random = seed.map_or_else(Random::from_time, Random::new);
+        let mut board = Board::empty();
+
+        let mut sizes: Vec<usize> = [
+            vec![0; PluginConstants::SMALL_FISH],
+            vec![1; PluginConstants::MEDIUM_FISH],
+            vec![2; PluginConstants::LARGE_FISH],
+        ].concat();
+        random.shuffle(&mut sizes);
+
+        let last = PluginConstants::BOARD_WIDTH as isize - 1;
+        let edge = (1..last).map(|y| (0, y)).chain((1..last).map(|y| (last, y)));
+        for ((x, y), size) in edge.zip(sizes) {
+            board.set_field(&Coordinate {x, y}, TeamEnum::One.get_fish_types()[size]);
+            board.set_field(&Coordinate {x: y, y: x}, TeamEnum::Two.get_fish_types()[size]);
+        }
+
+        // squids are point symmetric in the inner area, off the diagonals and not next to each other
+        let candidates: Vec<Coordinate> = (2..last - 1)
+            .flat_map(|x| (2..last - 1).map(move |y| Coordinate {x, y}))
+            .filter(|c| c.x != c.y && c.x + c.y != last)
+            .filter(|c| (c.x - (last - c.x)).abs() > 1 || (c.y - (last - c.y)).abs() > 1)
+            .collect();
+
+        let squid = *random.choose(&candidates).expect("Inner area has free fields");
+        board.set_field(&squid, FieldType::Squid);
+        board.set_field(&Coordinate {x: last - squid.x, y: last - squid.y}, FieldType::Squid);
+
+        board
     }
 
     fn __str__(&self) -> String {self.to_string()}
     fn __repr__(&self) -> String {format!("{:?}", self)}
     fn __eq__(&self, other: &Board) -> bool {self == other}
     fn __ne__(&self, other: &Board) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __copy__(&self) -> Board {self.clone()}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Board {self.clone()}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
     fn deepcopy(&self) -> Board {self.clone()}
 
-    pub fn get_field(&self, position: &Coordinate) -> Option<FieldType> {
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Board> {
+        binary::from_bytes(data)
+    }
 
-        let x = usize::try_from(position.x).ok()?;
-        let y = usize::try_from(position.y).ok()?;
+    // symmetries that swap the axes also swap the teams, so each team keeps its own start sides
+    pub fn transform(&self, symmetry: Symmetry) -> Board {
+        let mut board = Board::empty();
+
+        for position in bitboard::coordinates(self.occupied_mask()) {
+            let field = self.get_field(&position).expect("Position is on the board");
+            board.set_field(&symmetry.transform_coordinate(&position), symmetry.transform_field(field));
+        }
 
-        self.map
-            .get(y)?
-            .get(x)
-            .cloned()
+        board
     }
 
-    pub fn get_fields_by_type(&self, field: FieldType) -> Vec<Coordinate> {
+    pub fn to_numpy(&self, py: Python<'_>) -> PyResult<PyObject> {
+        tensor::to_numpy(py, &self.planes(), &[PluginConstants::BOARD_PLANES, PluginConstants::BOARD_HEIGHT, PluginConstants::BOARD_WIDTH])
+    }
 
-        let mut positions: Vec<Coordinate> = vec![];
+    #[getter]
+    pub fn map(&self) -> Vec<Vec<FieldType>> {
+        (0..PluginConstants::BOARD_HEIGHT)
+            .map(|y| (0..PluginConstants::BOARD_WIDTH)
+                .map(|x| self.field_at(y * PluginConstants::BOARD_WIDTH + x))
+                .collect())
+            .collect()
+    }
 
-        for (y, row) in self.map.iter().enumerate() {
-            for (x, f) in row.iter().enumerate() {
-                if *f == field {
-                    positions.push(Coordinate {x: x as isize, y: y as isize});
-                }
-            }
-        }
+    #[setter]
+    pub fn set_map(&mut self, map: Vec<Vec<FieldType>>) -> PyResult<()> {
+        *self = Board::new(map)?;
+        Ok(())
+    }
+
+    #[getter]
+    pub fn zobrist(&self) -> u64 {
+        self.zobrist
+    }
 
-        positions
+    pub fn get_field(&self, position: &Coordinate) -> Option<FieldType> {
+        bitboard::index_of(position).map(|i| self.field_at(i))
+    }
+
+    pub fn get_fields_by_type(&self, field: FieldType) -> Vec<Coordinate> {
+        bitboard::coordinates(self.mask_of(field)).collect()
     }
 
     pub fn get_fields_in_direction(&self, position: &Coordinate, direction: &Direction) -> Vec<FieldType> {
+        self.fields_along(position, direction, |i| bitboard::ray(i, direction))
+    }
 
-        let mut fields: Vec<FieldType> = Vec::new();
+    pub fn get_fields_on_line(&self, position: &Coordinate, direction: &Direction) -> Vec<FieldType> {
+        self.fields_along(position, direction, |i| bitboard::line(i, direction))
+    }
+
+    pub fn get_fish_on_line(&self, position: &Coordinate, direction: &Direction) -> Vec<FieldType> {
+        self.fields_along(position, direction, |i| bitboard::line(i, direction) & self.fish_mask())
+    }
+}
 
-        for scalar in 1..PluginConstants::BOARD_WIDTH {
-            let new_pos = position.add_vector(&direction.to_vector().scale(scalar as isize));
-            if let Some(field) = self.get_field(&new_pos) {
-                fields.push(field);
-            } else {break}
+// rust exclusive methods
+impl Board {
+    fn slot_of(field: FieldType) -> Option<(usize, usize)> {
+        match field {
+            FieldType::OneS => Some((0, 0)),
+            FieldType::OneM => Some((0, 1)),
+            FieldType::OneL => Some((0, 2)),
+            FieldType::TwoS => Some((1, 0)),
+            FieldType::TwoM => Some((1, 1)),
+            FieldType::TwoL => Some((1, 2)),
+            FieldType::Squid | FieldType::Empty => None,
         }
+    }
 
-        fields
+    pub fn empty() -> Board {
+        Board { fish: [[0; 3]; 2], squids: 0, zobrist: 0 }
     }
 
-    pub fn get_fields_on_line(&self, position: &Coordinate, direction: &Direction) -> Vec<FieldType> {
+    // the fields of the mask around the position, in the order they are passed when walking in the direction
+    fn fields_along(&self, position: &Coordinate, direction: &Direction, mask: impl Fn(usize) -> Bitboard) -> Vec<FieldType> {
+        bitboard::index_of(position).map_or_else(Vec::new, |i| {
+            bitboard::along(mask(i), direction).into_iter().map(|j| self.field_at(j)).collect()
+        })
+    }
 
-        let mut fields: Vec<FieldType> = Vec::new();
+    fn field_at(&self, index: usize) -> FieldType {
+        let bit: Bitboard = 1 << index;
 
-        if let Some(field) = self.get_field(position) {
-            fields.push(field);
-        } else {return fields}
+        if self.squids & bit != 0 {
+            return FieldType::Squid;
+        }
+
+        for (team, sizes) in [TeamEnum::One, TeamEnum::Two].iter().zip(self.fish.iter()) {
+            for (field, mask) in team.get_fish_types().into_iter().zip(sizes.iter()) {
+                if mask & bit != 0 {
+                    return field;
+                }
+            }
+        }
 
-        let in_dir = self.get_fields_in_direction(position, direction);
-        let in_mirror = self.get_fields_in_direction(position, &direction.to_mirrored());
+        FieldType::Empty
+    }
+
+    pub fn set_field(&mut self, position: &Coordinate, field: FieldType) {
+        let Some(index) = bitboard::index_of(position) else {
+            return;
+        };
+        let bit: Bitboard = 1 << index;
+
+        self.zobrist ^= zobrist::field_key(index, self.field_at(index)) ^ zobrist::field_key(index, field);
+
+        for sizes in self.fish.iter_mut() {
+            for mask in sizes.iter_mut() {
+                *mask &= !bit;
+            }
+        }
+        self.squids &= !bit;
 
-        // merge vecs
-        for f in in_dir {
-            fields.push(f);
+        match field {
+            FieldType::Squid => self.squids |= bit,
+            FieldType::Empty => {},
+            _ => {
+                let (team, size) = Self::slot_of(field).expect("Field is a fish");
+                self.fish[team][size] |= bit;
+            }
         }
+    }
 
-        for f in in_mirror {
-            fields.insert(0, f);
+    pub fn mask_of(&self, field: FieldType) -> Bitboard {
+        match field {
+            FieldType::Squid => self.squids,
+            FieldType::Empty => !self.occupied_mask() & bitboard::FULL,
+            _ => {
+                let (team, size) = Self::slot_of(field).expect("Field is a fish");
+                self.fish[team][size]
+            }
         }
+    }
 
-        fields
+    pub fn squid_mask(&self) -> Bitboard {
+        self.squids
     }
 
-    pub fn get_fish_on_line(&self, position: &Coordinate, direction: &Direction) -> Vec<FieldType> {
+    pub fn team_mask(&self, team: &TeamEnum) -> Bitboard {
+        let sizes = &self.fish[*team as usize];
+        sizes[0] | sizes[1] | sizes[2]
+    }
 
-        let fields_on_line = self.get_fields_on_line(position, direction);
+    pub fn fish_mask(&self) -> Bitboard {
+        self.team_mask(&TeamEnum::One) | self.team_mask(&TeamEnum::Two)
+    }
 
-        let fish_only: Vec<FieldType> = fields_on_line.iter()
-            .filter(|&f| matches!(f, 
-                FieldType::OneS | FieldType::OneM | FieldType::OneL |
-                FieldType::TwoS | FieldType::TwoM | FieldType::TwoL
-            ))
-            .cloned()
-            .collect();
+    pub fn occupied_mask(&self) -> Bitboard {
+        self.fish_mask() | self.squids
+    }
 
-        fish_only
+    pub fn fish_count_on_line(&self, position: &Coordinate, direction: &Direction) -> usize {
+        bitboard::index_of(position)
+            .map_or(0, |i| (bitboard::line(i, direction) & self.fish_mask()).count_ones() as usize)
+    }
+
+    // one plane per field type in declaration order, each indexed by y and then x
+    pub fn planes(&self) -> Vec<f32> {
+        let fields = PluginConstants::BOARD_WIDTH * PluginConstants::BOARD_HEIGHT;
+        let mut planes = vec![0.0; PluginConstants::BOARD_PLANES * fields];
+
+        for index in 0..fields {
+            let field = self.field_at(index);
+            planes[field as usize * fields + index] = 1.0;
+        }
+
+        planes
+    }
+}
+
+impl std::fmt::Debug for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Board")
+            .field("map", &self.map())
+            .finish()
     }
 }
 
 impl std::fmt::Display for Board {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f)?;
-        for row in self.map.iter().rev() {
+        for row in self.map().iter().rev() {
             for field in row {
                 write!(f, "{} ", *field)?;
             }
@@ -120,3 +284,30 @@ impl std::fmt::Display for Board {
         Ok(())
     }
 }
+
+// serialized like the map, with the bitboards and the zobrist key rebuilt when reading
+#[cfg(feature = "serde")]
+impl From<Board> for Vec<Vec<FieldType>> {
+    fn from(board: Board) -> Self {
+        board.map()
+    }
+}
+
+// maps of the wrong size are rejected instead of cut off or filled with empty fields
+impl TryFrom<Vec<Vec<FieldType>>> for Board {
+    type Error = String;
+
+    fn try_from(map: Vec<Vec<FieldType>>) -> Result<Self, Self::Error> {
+        if map.len() != PluginConstants::BOARD_HEIGHT || map.iter().any(|row| row.len() != PluginConstants::BOARD_WIDTH) {
+            return Err(format!("board has to be {}x{} fields", PluginConstants::BOARD_WIDTH, PluginConstants::BOARD_HEIGHT));
+        }
+
+        let mut board = Board::empty();
+        for (y, row) in map.iter().enumerate() {
+            for (x, field) in row.iter().enumerate() {
+                board.set_field(&Coordinate {x: x as isize, y: y as isize}, *field);
+            }
+        }
+        Ok(board)
+    }
+}
@@ -1,3 +1,203 @@
-use pyo3::{exceptions::PyException, *};
+use pyo3::{exceptions::PyException, prelude::PyAnyMethods, *};
 
-create_exception!(_socha, PiranhasError, PyException);
\ No newline at end of file
+use crate::plugin2026::{r#move::Move, utils::coordinate::Coordinate};
+
+create_exception!(_socha, PiranhasError, PyException);
+
+create_exception!(_socha, StartOutOfBoundsError, PiranhasError);
+create_exception!(_socha, TargetOutOfBoundsError, PiranhasError);
+create_exception!(_socha, NotAFishError, PiranhasError);
+create_exception!(_socha, WrongTeamError, PiranhasError);
+create_exception!(_socha, BlockedBySquidError, PiranhasError);
+create_exception!(_socha, OwnFishOnTargetError, PiranhasError);
+create_exception!(_socha, JumpsOverOpponentError, PiranhasError);
+create_exception!(_socha, GameOverError, PiranhasError);
+create_exception!(_socha, StaleLastMoveError, PiranhasError);
+create_exception!(_socha, StaleUndoTokenError, PiranhasError);
+
+create_exception!(_socha, ProtocolError, PiranhasError);
+create_exception!(_socha, InvalidNotationError, PiranhasError);
+
+// the reason why a move cannot be executed, usable without a python interpreter
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MoveError {
+    StartOutOfBounds { start: Coordinate },
+    TargetOutOfBounds { start: Coordinate, target: Coordinate },
+    NotAFish { start: Coordinate },
+    WrongTeam { start: Coordinate },
+    BlockedBySquid { start: Coordinate, target: Coordinate },
+    OwnFishOnTarget { start: Coordinate, target: Coordinate },
+    // over is the first fish of the other team between start and target
+    JumpsOverOpponent { start: Coordinate, target: Coordinate, over: Coordinate },
+    GameOver { start: Coordinate },
+    // the move was chosen after the expected last move, but the game's last move is the actual one
+    StaleLastMove { start: Coordinate, expected: Move, actual: Option<Move> },
+    // the undo token belongs to another move than the last one, start is the one of the token's move
+    StaleUndoToken { start: Coordinate },
+}
+
+impl MoveError {
+    pub fn start(&self) -> &Coordinate {
+        match self {
+            MoveError::StartOutOfBounds { start }
+            | MoveError::TargetOutOfBounds { start, .. }
+            | MoveError::NotAFish { start }
+            | MoveError::WrongTeam { start }
+            | MoveError::BlockedBySquid { start, .. }
+            | MoveError::OwnFishOnTarget { start, .. }
+            | MoveError::JumpsOverOpponent { start, .. }
+            | MoveError::GameOver { start }
+            | MoveError::StaleLastMove { start, .. }
+            | MoveError::StaleUndoToken { start } => start,
+        }
+    }
+
+    pub fn target(&self) -> Option<&Coordinate> {
+        match self {
+            MoveError::TargetOutOfBounds { target, .. }
+            | MoveError::BlockedBySquid { target, .. }
+            | MoveError::OwnFishOnTarget { target, .. }
+            | MoveError::JumpsOverOpponent { target, .. } => Some(target),
+            MoveError::StartOutOfBounds { .. }
+            | MoveError::NotAFish { .. }
+            | MoveError::WrongTeam { .. }
+            | MoveError::GameOver { .. }
+            | MoveError::StaleLastMove { .. }
+            | MoveError::StaleUndoToken { .. } => None,
+        }
+    }
+
+    pub fn over(&self) -> Option<&Coordinate> {
+        match self {
+            MoveError::JumpsOverOpponent { over, .. } => Some(over),
+            _ => None,
+        }
+    }
+
+    fn to_py_err(&self) -> PyErr {
+        let message = self.to_string();
+        match self {
+            MoveError::StartOutOfBounds { .. } => StartOutOfBoundsError::new_err(message),
+            MoveError::TargetOutOfBounds { .. } => TargetOutOfBoundsError::new_err(message),
+            MoveError::NotAFish { .. } => NotAFishError::new_err(message),
+            MoveError::WrongTeam { .. } => WrongTeamError::new_err(message),
+            MoveError::BlockedBySquid { .. } => BlockedBySquidError::new_err(message),
+            MoveError::OwnFishOnTarget { .. } => OwnFishOnTargetError::new_err(message),
+            MoveError::JumpsOverOpponent { .. } => JumpsOverOpponentError::new_err(message),
+            MoveError::GameOver { .. } => GameOverError::new_err(message),
+            MoveError::StaleLastMove { .. } => StaleLastMoveError::new_err(message),
+            MoveError::StaleUndoToken { .. } => StaleUndoTokenError::new_err(message),
+        }
+    }
+}
+
+impl std::fmt::Display for MoveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MoveError::StartOutOfBounds { start } =>
+                write!(f, "Start position {} is out of bounds", start),
+            MoveError::TargetOutOfBounds { target, .. } =>
+                write!(f, "Target position {} is out of bounds", target),
+            MoveError::NotAFish { start } =>
+                write!(f, "Start position {} is not on fish field", start),
+            MoveError::WrongTeam { start } =>
+                write!(f, "Fish on {} does not belong to the team on turn", start),
+            MoveError::BlockedBySquid { target, .. } =>
+                write!(f, "Cannot swim onto squid on {}", target),
+            MoveError::OwnFishOnTarget { target, .. } =>
+                write!(f, "Cannot swim onto field of own team on {}", target),
+            MoveError::JumpsOverOpponent { over, .. } =>
+                write!(f, "Cannot swim over other team's fish on {}", over),
+            MoveError::GameOver { .. } =>
+                write!(f, "Cannot move after the game is over"),
+            MoveError::StaleLastMove { expected, actual: Some(actual), .. } =>
+                write!(f, "Move was chosen after {}, but the last move of the game is {}", expected, actual),
+            MoveError::StaleLastMove { expected, actual: None, .. } =>
+                write!(f, "Move was chosen after {}, but the game has no last move", expected),
+            MoveError::StaleUndoToken { .. } =>
+                write!(f, "Undo token does not belong to the last move"),
+        }
+    }
+}
+
+impl std::error::Error for MoveError {}
+
+// the exception carries the coordinates as attributes start, target and over, a stale last move also expected and actual
+impl From<MoveError> for PyErr {
+    fn from(error: MoveError) -> Self {
+        Python::with_gil(|py| {
+            let err = error.to_py_err();
+            let value = err.value_bound(py);
+
+            let attributes = [("start", Some(error.start())), ("target", error.target()), ("over", error.over())];
+            for (name, coordinate) in attributes {
+                if let Err(e) = value.setattr(name, coordinate.copied().into_py(py)) {
+                    return e;
+                }
+            }
+
+            if let MoveError::StaleLastMove { expected, actual, .. } = &error {
+                for (name, move_) in [("expected", Some(expected)), ("actual", actual.as_ref())] {
+                    if let Err(e) = value.setattr(name, move_.cloned().into_py(py)) {
+                        return e;
+                    }
+                }
+            }
+
+            err
+        })
+    }
+}
+
+// a server message that is not well-formed or does not describe a valid game
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct XmlError {
+    pub message: String,
+}
+
+impl XmlError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self { message: message.into() }
+    }
+}
+
+impl std::fmt::Display for XmlError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid server message: {}", self.message)
+    }
+}
+
+impl std::error::Error for XmlError {}
+
+impl From<XmlError> for PyErr {
+    fn from(error: XmlError) -> Self {
+        ProtocolError::new_err(error.to_string())
+    }
+}
+
+// text in position or move notation that cannot be read, the column counts characters from 0
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NotationError {
+    pub message: String,
+    pub column: usize,
+}
+
+impl NotationError {
+    pub fn new(message: impl Into<String>, column: usize) -> Self {
+        Self { message: message.into(), column }
+    }
+}
+
+impl std::fmt::Display for NotationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid notation at column {}: {}", self.column, self.message)
+    }
+}
+
+impl std::error::Error for NotationError {}
+
+impl From<NotationError> for PyErr {
+    fn from(error: NotationError) -> Self {
+        InvalidNotationError::new_err(error.to_string())
+    }
+}
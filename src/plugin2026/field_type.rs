@@ -1,9 +1,12 @@
+use std::borrow::Cow;
+
 use pyo3::*;
 
-use crate::plugin2026::utils::team::TeamEnum;
+use crate::plugin2026::utils::{binary, hashing, team::TeamEnum};
 
-#[pyclass]
+#[pyclass(module = "socha._socha")]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FieldType {
     OneS,
     OneM,
@@ -21,6 +24,19 @@ impl FieldType {
     fn __repr__(&self) -> String {format!("{:?}", self)}
     fn __eq__(&self, other: &FieldType) -> bool {self == other}
     fn __ne__(&self, other: &FieldType) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __copy__(&self) -> FieldType {*self}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> FieldType {*self}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<FieldType> {
+        binary::from_bytes(data)
+    }
     
     pub fn get_value(&self) -> usize {
         match self {
@@ -0,0 +1,117 @@
+use std::borrow::Cow;
+
+use pyo3::*;
+
+use crate::plugin2026::utils::{binary, hashing, team::TeamEnum};
+
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WinReason {
+    SwarmConnected,
+    BothConnected,
+    RoundLimit,
+    // the opponent took longer than the time limit of a move
+    Timeout,
+    // the opponent sent a move that is not allowed
+    IllegalMove
+}
+
+#[pymethods]
+impl WinReason {
+    fn __str__(&self) -> String {self.to_string()}
+    fn __repr__(&self) -> String {format!("{:?}", self)}
+    fn __eq__(&self, other: &WinReason) -> bool {self == other}
+    fn __ne__(&self, other: &WinReason) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __copy__(&self) -> WinReason {*self}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> WinReason {*self}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<WinReason> {
+        binary::from_bytes(data)
+    }
+}
+
+impl std::fmt::Display for WinReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SwarmConnected => write!(f, "Alle Fische in einem Schwarm"),
+            Self::BothConnected => write!(f, "Beide Teams in derselben Runde verbunden"),
+            Self::RoundLimit => write!(f, "Rundenlimit erreicht"),
+            Self::Timeout => write!(f, "Zeitlimit überschritten"),
+            Self::IllegalMove => write!(f, "Ungültiger Zug")
+        }
+    }
+}
+
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GameResult {
+    #[pyo3(get)]
+    pub winner: Option<TeamEnum>,
+    #[pyo3(get)]
+    pub reason: WinReason,
+    #[pyo3(get)]
+    pub team_one_score: usize,
+    #[pyo3(get)]
+    pub team_two_score: usize,
+}
+
+#[pymethods]
+impl GameResult {
+    #[new]
+    #[pyo3(signature = (winner, reason, team_one_score, team_two_score))]
+    pub fn new(winner: Option<TeamEnum>, reason: WinReason, team_one_score: usize, team_two_score: usize) -> Self {
+        Self {
+            winner,
+            reason,
+            team_one_score,
+            team_two_score,
+        }
+    }
+
+    fn __str__(&self) -> String {self.to_string()}
+    fn __repr__(&self) -> String {format!("{:?}", self)}
+    fn __eq__(&self, other: &GameResult) -> bool {self == other}
+    fn __ne__(&self, other: &GameResult) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __copy__(&self) -> GameResult {self.clone()}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> GameResult {self.clone()}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
+    fn deepcopy(&self) -> GameResult {self.clone()}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<GameResult> {
+        binary::from_bytes(data)
+    }
+
+    pub fn is_draw(&self) -> bool {
+        self.winner.is_none()
+    }
+
+    pub fn score_of(&self, team: &TeamEnum) -> usize {
+        match team {
+            TeamEnum::One => self.team_one_score,
+            TeamEnum::Two => self.team_two_score
+        }
+    }
+}
+
+impl std::fmt::Display for GameResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.winner {
+            Some(team) => write!(f, "Gewinner: {}", team)?,
+            None => write!(f, "Unentschieden")?,
+        }
+        write!(f, " ({}), Punkte: {} zu {}", self.reason, self.team_one_score, self.team_two_score)
+    }
+}
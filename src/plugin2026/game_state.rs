@@ -1,14 +1,63 @@
+use std::borrow::Cow;
+
 use pyo3::*;
 
 use crate::plugin2026::{
-    board::Board, errors::PiranhasError, field_type::FieldType, r#move::Move, rules_engine::RulesEngine, utils::{
+    board::Board, errors::{MoveError, NotationError, PiranhasError}, field_type::FieldType, game_result::{GameResult, WinReason}, notation, r#move::Move, rules_engine::RulesEngine, utils::{
+        binary,
+        bitboard,
+        constants::PluginConstants,
         coordinate::Coordinate,
-        direction::Direction
+        direction::Direction,
+        hashing,
+        symmetry::Symmetry,
+        team::TeamEnum,
+        tensor,
+        zobrist
     }
 };
+#[cfg(feature = "serde")]
+use crate::plugin2026::utils::json;
+
+// everything GameState::unmake_move needs to take back a move made by GameState::make_move
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UndoToken {
+    #[pyo3(get)]
+    pub move_: Move,
+    #[pyo3(get)]
+    pub target: Coordinate,
+    #[pyo3(get)]
+    pub captured: FieldType,
+    #[pyo3(get)]
+    pub last_move: Option<Move>,
+    #[pyo3(get)]
+    pub turn: usize,
+}
+
+#[pymethods]
+impl UndoToken {
+    fn __repr__(&self) -> String {format!("{:?}", self)}
+    fn __eq__(&self, other: &UndoToken) -> bool {self == other}
+    fn __ne__(&self, other: &UndoToken) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __copy__(&self) -> UndoToken {self.clone()}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> UndoToken {self.clone()}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<UndoToken> {
+        binary::from_bytes(data)
+    }
+}
 
-#[pyclass]
+#[pyclass(module = "socha._socha")]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GameState {
     #[pyo3(get, set)]
     pub board: Board,
@@ -33,21 +82,86 @@ impl GameState {
     fn __repr__(&self) -> String {format!("{:?}", self)}
     fn __eq__(&self, other: &GameState) -> bool {self == other}
     fn __ne__(&self, other: &GameState) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __copy__(&self) -> GameState {self.clone()}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> GameState {self.clone()}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
     fn deepcopy(&self) -> GameState {self.clone()}
 
-    pub fn set_board_field(&mut self, position: &Coordinate, field: FieldType) -> Result<(), PyErr> {
-        let x = position.x as usize;
-        let y = position.y as usize;
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<GameState> {
+        binary::from_bytes(data)
+    }
+
+    #[getter]
+    pub fn zobrist(&self) -> u64 {
+        match RulesEngine::get_team_on_turn(self.turn) {
+            TeamEnum::One => self.board.zobrist(),
+            TeamEnum::Two => self.board.zobrist() ^ zobrist::TEAM_TWO_KEY,
+        }
+    }
 
-        if y >= self.board.map.len() || x >= self.board.map[0].len() {
+    pub fn set_board_field(&mut self, position: &Coordinate, field: FieldType) -> Result<(), PyErr> {
+        if !RulesEngine::is_in_bounds(position) {
             return Err(PiranhasError::new_err("Position not in bounds of map"));
         }
 
-        self.board.map[y][x] = field;
+        self.board.set_field(position, field);
 
         Ok(())
     }
 
+    pub fn to_notation(&self) -> Result<String, MoveError> {
+        notation::state_to_notation(self)
+    }
+
+    #[staticmethod]
+    pub fn from_notation(notation: &str) -> Result<GameState, NotationError> {
+        notation::parse_state(notation)
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        json::to_json(self)
+    }
+
+    #[cfg(feature = "serde")]
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<GameState> {
+        json::from_json(json)
+    }
+
+    // with swapped teams the other team would be on turn, and the turn decides about the round limit and the swarm check,
+    // so there is no equivalent state and only Board.transform can swap the teams
+    pub fn transform(&self, symmetry: Symmetry) -> Result<GameState, PyErr> {
+        if symmetry.swaps_teams() {
+            return Err(PiranhasError::new_err("Symmetry swaps the teams, so the transformed state is not equivalent"));
+        }
+
+        Ok(GameState {
+            board: self.board.transform(symmetry),
+            turn: self.turn,
+            last_move: self.last_move.as_ref().map(|m| m.transform(symmetry)),
+        })
+    }
+
+    // the smallest of all equivalent states represents all of them
+    pub fn canonical(&self) -> (GameState, Symmetry) {
+        Symmetry::all_symmetries()
+            .into_iter()
+            .filter_map(|s| self.transform(s).ok().map(|state| (state, s)))
+            .min_by(|(a, _), (b, _)| a.board.cmp(&b.board).then_with(|| a.last_move.cmp(&b.last_move)))
+            .expect("Identity keeps the teams")
+    }
+
+    pub fn to_planes(&self, py: Python<'_>) -> PyResult<PyObject> {
+        tensor::to_numpy(py, &self.planes(), &[PluginConstants::STATE_PLANES, PluginConstants::BOARD_HEIGHT, PluginConstants::BOARD_WIDTH])
+    }
+
     pub fn possible_moves_for(&self, start: &Coordinate) -> Vec<Move> {
         let mut moves: Vec<Move> = Vec::new();
 
@@ -63,20 +177,16 @@ impl GameState {
 
     pub fn possible_moves(&self) -> Vec<Move> {
         let mut moves: Vec<Move> = Vec::new();
-        let mut fish: Vec<Coordinate> = Vec::new();
+        let fish = self.board.team_mask(&RulesEngine::get_team_on_turn(self.turn));
 
-        for f in RulesEngine::get_team_on_turn(self.turn).get_fish_types() {
-            fish.append(&mut self.board.get_fields_by_type(f));
-        }
-        
-        for f in fish {
+        for f in bitboard::coordinates(fish) {
             moves.extend(self.possible_moves_for(&f));
         }
 
         moves
     }
 
-    pub fn perform_move(&self, move_: &Move) -> Result<GameState, PyErr> {
+    pub fn perform_move(&self, move_: &Move) -> Result<GameState, MoveError> {
 
         let mut new_game_state = self.clone();
         new_game_state.perform_move_mut(move_)?;
@@ -84,26 +194,172 @@ impl GameState {
         Ok(new_game_state)
     }
     
-    pub fn perform_move_mut(&mut self, move_: &Move) -> Result<(), PyErr> {
+    pub fn perform_move_mut(&mut self, move_: &Move) -> Result<(), MoveError> {
+        self.make_move(move_).map(|_| ())
+    }
+
+    // checks everything the server checks before accepting a move, and that the move follows last_move if one is given
+    #[pyo3(signature = (move_, last_move=None))]
+    pub fn validate_move(&self, move_: &Move, last_move: Option<Move>) -> Result<(), MoveError> {
+        if let Some(expected) = last_move {
+            if self.last_move.as_ref() != Some(&expected) {
+                return Err(MoveError::StaleLastMove { start: move_.start, expected, actual: self.last_move.clone() });
+            }
+        }
+
+        self.check_move(move_)
+    }
+
+    pub fn is_legal(&self, move_: &Move) -> bool {
+        self.check_move(move_).is_ok()
+    }
+
+    pub fn make_move(&mut self, move_: &Move) -> Result<UndoToken, MoveError> {
+
+        self.check_move(move_)?;
 
-        RulesEngine::can_execute_move(&self.board, move_)
-            .map_err(|e| {
-                let full_error = e.to_string();
-                let clean_error = full_error.strip_prefix("PiranhasError:").unwrap_or(&full_error).trim();
-                PiranhasError::new_err(format!("Cannot execute move: {}", clean_error))
-            })?;
-    
         let target = RulesEngine::target_position(&self.board, move_);
-        let mut new_board = self.board.clone();
-        new_board.map[target.y as usize][target.x as usize] = self.board.get_field(&move_.start).unwrap();
-        new_board.map[move_.start.y as usize][move_.start.x as usize] = FieldType::Empty;
+        let fish = self.board.get_field(&move_.start).unwrap();
+        let captured = self.board.get_field(&target).unwrap();
+        self.board.set_field(&target, fish);
+        self.board.set_field(&move_.start, FieldType::Empty);
 
-        self.board = new_board;
+        let token = UndoToken {
+            move_: move_.clone(),
+            target,
+            captured,
+            last_move: self.last_move.replace(move_.clone()),
+            turn: self.turn,
+        };
         self.turn += 1;
-        self.last_move = Some(move_.clone());
+
+        Ok(token)
+    }
+
+    pub fn unmake_move(&mut self, token: &UndoToken) -> Result<(), MoveError> {
+        // tokens have to be taken back in reverse order of their moves
+        if self.turn != token.turn + 1 || self.last_move.as_ref() != Some(&token.move_) {
+            return Err(MoveError::StaleUndoToken { start: token.move_.start });
+        }
+
+        let fish = self.board.get_field(&token.target).unwrap();
+        self.board.set_field(&token.move_.start, fish);
+        self.board.set_field(&token.target, token.captured);
+
+        self.turn = token.turn;
+        self.last_move = token.last_move.clone();
 
         Ok(())
     }
+
+    // counts the positions reached after exactly depth moves, finished games are not continued
+    pub fn perft(&self, depth: usize) -> u64 {
+        self.clone().perft_mut(depth)
+    }
+
+    // the perft count below each possible move, to find where two move generators differ
+    pub fn divide(&self, depth: usize) -> Vec<(Move, u64)> {
+        if self.is_over() {
+            return vec![];
+        }
+
+        let mut state = self.clone();
+        state.possible_moves()
+            .into_iter()
+            .map(|move_| {
+                let token = state.make_move(&move_).expect("Possible moves are legal");
+                let nodes = state.perft_mut(depth.saturating_sub(1));
+                state.unmake_move(&token).expect("Token belongs to the last move");
+                (move_, nodes)
+            })
+            .collect()
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.game_result().is_some()
+    }
+
+    pub fn winner(&self) -> Option<TeamEnum> {
+        self.game_result().and_then(|r| r.winner)
+    }
+
+    pub fn game_result(&self) -> Option<GameResult> {
+
+        // swarms are only checked after team two moved, so both teams had the same number of moves
+        let round_complete = self.turn.is_multiple_of(2);
+        let one_connected = round_complete && RulesEngine::is_swarm_connected(&self.board, &TeamEnum::One);
+        let two_connected = round_complete && RulesEngine::is_swarm_connected(&self.board, &TeamEnum::Two);
+
+        let reason = match (one_connected, two_connected) {
+            (true, false) | (false, true) => WinReason::SwarmConnected,
+            (true, true) => WinReason::BothConnected,
+            (false, false) if self.turn >= PluginConstants::ROUND_LIMIT * 2 => WinReason::RoundLimit,
+            (false, false) => return None,
+        };
+
+        let one_score = RulesEngine::largest_swarm_value(&self.board, &TeamEnum::One);
+        let two_score = RulesEngine::largest_swarm_value(&self.board, &TeamEnum::Two);
+
+        let winner = match reason {
+            WinReason::SwarmConnected if one_connected => Some(TeamEnum::One),
+            WinReason::SwarmConnected => Some(TeamEnum::Two),
+            _ => RulesEngine::compare_swarms(&self.board),
+        };
+
+        Some(GameResult::new(winner, reason, one_score, two_score))
+    }
+}
+
+// rust exclusive methods
+impl GameState {
+    // the board planes, then a plane of ones if team one is on turn, then the share of the round limit played
+    pub fn planes(&self) -> Vec<f32> {
+        let fields = PluginConstants::BOARD_WIDTH * PluginConstants::BOARD_HEIGHT;
+        let on_turn = if RulesEngine::get_team_on_turn(self.turn) == TeamEnum::One { 1.0 } else { 0.0 };
+        let progress = self.turn as f32 / (PluginConstants::ROUND_LIMIT * 2) as f32;
+
+        let mut planes = self.board.planes();
+        planes.extend(std::iter::repeat_n(on_turn, fields));
+        planes.extend(std::iter::repeat_n(progress, fields));
+        planes
+    }
+
+    fn perft_mut(&mut self, depth: usize) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+        if self.is_over() {
+            return 0;
+        }
+
+        let moves = self.possible_moves();
+        if depth == 1 {
+            return moves.len() as u64;
+        }
+
+        moves.iter()
+            .map(|move_| {
+                let token = self.make_move(move_).expect("Possible moves are legal");
+                let nodes = self.perft_mut(depth - 1);
+                self.unmake_move(&token).expect("Token belongs to the last move");
+                nodes
+            })
+            .sum()
+    }
+
+    // the rules for the single move, without the more expensive check for the end of the game
+    fn check_move(&self, move_: &Move) -> Result<(), MoveError> {
+        if self.is_over() {
+            return Err(MoveError::GameOver { start: move_.start });
+        }
+
+        let team = self.board.get_field(&move_.start).and_then(|f| f.get_team());
+        if team.is_some_and(|t| t != RulesEngine::get_team_on_turn(self.turn)) {
+            return Err(MoveError::WrongTeam { start: move_.start });
+        }
+
+        RulesEngine::can_execute_move(&self.board, move_)
+    }
 }
 
 impl std::fmt::Display for GameState {
@@ -0,0 +1,221 @@
+use std::{borrow::Cow, time::{Duration, Instant}};
+
+use pyo3::{prelude::PyAnyMethods, *};
+
+use crate::plugin2026::{
+    game_result::{GameResult, WinReason},
+    game_state::GameState,
+    r#move::Move,
+    rules_engine::RulesEngine,
+    search::{mcts::MonteCarloSearcher, searcher::Searcher},
+    utils::{binary, hashing, team::TeamEnum}
+};
+
+// engines plan with this share of the time limit, the rest is left for their overhead
+const ENGINE_TIME_SHARE: f64 = 0.8;
+
+pub trait Player {
+    // None gives up the game, which counts like an illegal move
+    fn calculate_move(&mut self, state: &GameState, time_limit: Duration) -> PyResult<Option<Move>>;
+}
+
+impl Player for Searcher {
+    fn calculate_move(&mut self, state: &GameState, time_limit: Duration) -> PyResult<Option<Move>> {
+        let result = self.search_within(state, time_limit.mul_f64(ENGINE_TIME_SHARE));
+        Ok(result.map(|(m, _, _)| m))
+    }
+}
+
+impl Player for MonteCarloSearcher {
+    fn calculate_move(&mut self, state: &GameState, time_limit: Duration) -> PyResult<Option<Move>> {
+        let result = self.search(state, Some(time_limit.mul_f64(ENGINE_TIME_SHARE)), None);
+        Ok(result.map(|(m, _, _)| m))
+    }
+}
+
+// a player given from python, either one of the engines or an object like IClientHandler
+pub enum PyPlayer {
+    Searcher(Py<Searcher>),
+    MonteCarlo(Py<MonteCarloSearcher>),
+    Handler(PyObject),
+}
+
+impl PyPlayer {
+    pub fn from_py(player: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(searcher) = player.downcast::<Searcher>() {
+            return Ok(PyPlayer::Searcher(searcher.clone().unbind()));
+        }
+        if let Ok(mcts) = player.downcast::<MonteCarloSearcher>() {
+            return Ok(PyPlayer::MonteCarlo(mcts.clone().unbind()));
+        }
+        if !player.hasattr("calculate_move")? {
+            return Err(exceptions::PyTypeError::new_err("Player has to be a Searcher, a MonteCarloSearcher or implement calculate_move"));
+        }
+
+        Ok(PyPlayer::Handler(player.clone().unbind()))
+    }
+}
+
+impl Player for PyPlayer {
+    fn calculate_move(&mut self, state: &GameState, time_limit: Duration) -> PyResult<Option<Move>> {
+        Python::with_gil(|py| match self {
+            PyPlayer::Searcher(searcher) => {
+                let mut searcher = searcher.borrow_mut(py);
+                let searcher: &mut Searcher = &mut searcher;
+                py.allow_threads(|| searcher.calculate_move(state, time_limit))
+            },
+            PyPlayer::MonteCarlo(mcts) => {
+                let mut mcts = mcts.borrow_mut(py);
+                let mcts: &mut MonteCarloSearcher = &mut mcts;
+                py.allow_threads(|| mcts.calculate_move(state, time_limit))
+            },
+            // handlers get the state by on_update, just like from the server
+            PyPlayer::Handler(handler) => {
+                let handler = handler.bind(py);
+                if handler.hasattr("on_update")? {
+                    handler.call_method1("on_update", (state.clone(),))?;
+                }
+                handler.call_method0("calculate_move")?.extract::<Option<Move>>()
+            },
+        })
+    }
+}
+
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MatchResult {
+    #[pyo3(get)]
+    pub result: GameResult,
+    // one entry per turn, None where a team without any move skipped its turn
+    #[pyo3(get)]
+    pub moves: Vec<Option<Move>>,
+    // how long each move took, in the order of the moves and 0 for skipped turns
+    #[pyo3(get)]
+    pub move_times_ms: Vec<u64>,
+    #[pyo3(get)]
+    pub final_state: GameState,
+}
+
+#[pymethods]
+impl MatchResult {
+    fn __str__(&self) -> String {self.result.to_string()}
+    fn __repr__(&self) -> String {format!("{:?}", self)}
+    fn __eq__(&self, other: &MatchResult) -> bool {self == other}
+    fn __ne__(&self, other: &MatchResult) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __copy__(&self) -> MatchResult {self.clone()}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> MatchResult {self.clone()}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<MatchResult> {
+        binary::from_bytes(data)
+    }
+}
+
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone)]
+pub struct Match {
+    #[pyo3(get, set)]
+    pub start: GameState,
+    #[pyo3(get, set)]
+    pub time_limit_ms: u64,
+}
+
+#[pymethods]
+impl Match {
+    pub const DEFAULT_TIME_LIMIT_MS: u64 = 2000;
+
+    #[new]
+    #[pyo3(signature = (start, time_limit_ms=Match::DEFAULT_TIME_LIMIT_MS))]
+    pub fn new(start: GameState, time_limit_ms: u64) -> Self {
+        Self { start, time_limit_ms }
+    }
+
+    fn __repr__(&self) -> String {format!("{:?}", self)}
+    fn __copy__(&self) -> Match {self.clone()}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Match {self.clone()}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Match> {
+        binary::from_bytes(data)
+    }
+
+    #[pyo3(name = "play")]
+    fn py_play(&self, player_one: &Bound<'_, PyAny>, player_two: &Bound<'_, PyAny>) -> PyResult<MatchResult> {
+        let mut one = PyPlayer::from_py(player_one)?;
+        let mut two = PyPlayer::from_py(player_two)?;
+
+        self.play(&mut one, &mut two)
+    }
+}
+
+// rust exclusive methods
+impl Match {
+    // the engines stop on their own before the time limit, other players are only checked once they return,
+    // so a python player that never returns blocks the match
+    pub fn play(&self, one: &mut dyn Player, two: &mut dyn Player) -> PyResult<MatchResult> {
+        let time_limit = Duration::from_millis(self.time_limit_ms);
+
+        let mut state = self.start.clone();
+        let mut moves: Vec<Option<Move>> = vec![];
+        let mut move_times_ms: Vec<u64> = vec![];
+
+        let result = loop {
+            if let Some(result) = state.game_result() {
+                break result;
+            }
+
+            // a team without any move skips its turn, which is kept in the moves so that they replay the game
+            if state.possible_moves().is_empty() {
+                state.turn += 1;
+                moves.push(None);
+                move_times_ms.push(0);
+                continue;
+            }
+
+            let team = RulesEngine::get_team_on_turn(state.turn);
+            let player: &mut dyn Player = match team {
+                TeamEnum::One => &mut *one,
+                TeamEnum::Two => &mut *two,
+            };
+
+            let started = Instant::now();
+            let move_ = player.calculate_move(&state, time_limit)?;
+            let elapsed = started.elapsed();
+            move_times_ms.push(elapsed.as_millis() as u64);
+
+            if elapsed > time_limit {
+                break Self::forfeit(&state, team, WinReason::Timeout);
+            }
+
+            match move_.filter(|m| state.is_legal(m)) {
+                Some(move_) => {
+                    state.perform_move_mut(&move_).expect("Move was checked to be legal");
+                    moves.push(Some(move_));
+                },
+                None => break Self::forfeit(&state, team, WinReason::IllegalMove),
+            }
+        };
+
+        Ok(MatchResult { result, moves, move_times_ms, final_state: state })
+    }
+
+    fn forfeit(state: &GameState, loser: TeamEnum, reason: WinReason) -> GameResult {
+        GameResult::new(
+            Some(loser.opponent()),
+            reason,
+            RulesEngine::largest_swarm_value(&state.board, &TeamEnum::One),
+            RulesEngine::largest_swarm_value(&state.board, &TeamEnum::Two)
+        )
+    }
+}
@@ -1,16 +1,27 @@
-use std::fmt::Debug;
+use std::{borrow::Cow, fmt::Debug};
 
-use pyo3::*;
+use pyo3::{exceptions::PyValueError, *};
 
 use crate::plugin2026::{
+    board::Board,
+    errors::{MoveError, NotationError},
+    notation,
     utils::{
+        binary,
+        bitboard,
+        constants::PluginConstants,
         coordinate::Coordinate,
-        direction::Direction
+        direction::Direction,
+        hashing,
+        symmetry::Symmetry
     }
 };
 
-#[pyclass]
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+const DIRECTIONS: usize = 8;
+
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Move {
     #[pyo3(get, set)]
     pub start: Coordinate,
@@ -31,7 +42,64 @@ impl Move {
     fn __repr__(&self) -> String {format!("{:?}", self)}
     fn __eq__(&self, other: &Move) -> bool {self == other}
     fn __ne__(&self, other: &Move) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __lt__(&self, other: &Move) -> bool {self < other}
+    fn __le__(&self, other: &Move) -> bool {self <= other}
+    fn __gt__(&self, other: &Move) -> bool {self > other}
+    fn __ge__(&self, other: &Move) -> bool {self >= other}
+    fn __copy__(&self) -> Move {self.clone()}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Move {self.clone()}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
     fn deepcopy(&self) -> Move {self.clone()}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Move> {
+        binary::from_bytes(data)
+    }
+
+    // a4>R, or a4>R=c4 with the target on the given board
+    #[pyo3(signature = (board=None))]
+    pub fn to_notation(&self, board: Option<&Board>) -> Result<String, MoveError> {
+        match board {
+            Some(board) => notation::move_to_long_notation(self, board),
+            None => notation::move_to_notation(self),
+        }
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (notation, board=None))]
+    pub fn parse(notation: &str, board: Option<&Board>) -> Result<Move, NotationError> {
+        notation::parse_move(notation, 0, board)
+    }
+
+    pub fn transform(&self, symmetry: Symmetry) -> Move {
+        Move {
+            start: self.start.transform(symmetry),
+            direction: self.direction.transform(symmetry),
+        }
+    }
+
+    // the index of the start field times the 8 directions, plus the direction in declaration order
+    pub fn to_index(&self) -> Result<usize, MoveError> {
+        let field = bitboard::index_of(&self.start).ok_or(MoveError::StartOutOfBounds { start: self.start })?;
+        Ok(field * DIRECTIONS + self.direction as usize)
+    }
+
+    #[staticmethod]
+    pub fn from_index(index: usize) -> PyResult<Move> {
+        if index >= PluginConstants::MOVE_INDICES {
+            return Err(PyValueError::new_err(format!("Move index {} is out of range", index)));
+        }
+
+        Ok(Move {
+            start: bitboard::coordinate_of(index / DIRECTIONS),
+            direction: Direction::all_directions()[index % DIRECTIONS],
+        })
+    }
 }
 
 impl std::fmt::Display for Move {
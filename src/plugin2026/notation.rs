@@ -0,0 +1,287 @@
+// a single line notation for positions, similar to FEN in chess
+//
+// "1msllmmss1/M8S/.../1sllsmsls1 12 a4>R" lists the rows from the top (y = 9) to the bottom (y = 0), separated by '/'.
+// team one's fish are S, M and L, team two's are s, m and l, squids are *, and digits count empty fields.
+// the turn and the last move follow, '-' stands for no last move.
+// fields are named like in chess, the columns a to j are x = 0 to 9 and the rows 1 to 10 are y = 0 to 9.
+//
+// moves are written as <start>><direction> with the short direction names, like a4>R.
+// the long form a4>R=c4 adds the target, which depends on the fish on the board.
+
+use crate::plugin2026::{
+    board::Board,
+    errors::{MoveError, NotationError},
+    field_type::FieldType,
+    game_state::GameState,
+    r#move::Move,
+    rules_engine::RulesEngine,
+    utils::{
+        constants::PluginConstants,
+        coordinate::Coordinate,
+        direction::Direction
+    }
+};
+
+const WIDTH: usize = PluginConstants::BOARD_WIDTH;
+const HEIGHT: usize = PluginConstants::BOARD_HEIGHT;
+
+pub fn field_char(field: FieldType) -> Option<char> {
+    match field {
+        FieldType::OneS => Some('S'),
+        FieldType::OneM => Some('M'),
+        FieldType::OneL => Some('L'),
+        FieldType::TwoS => Some('s'),
+        FieldType::TwoM => Some('m'),
+        FieldType::TwoL => Some('l'),
+        FieldType::Squid => Some('*'),
+        FieldType::Empty => None,
+    }
+}
+
+pub fn field_from_char(c: char) -> Option<FieldType> {
+    FieldType::all_field_types().into_iter().find(|f| field_char(*f) == Some(c))
+}
+
+pub fn direction_name(direction: Direction) -> &'static str {
+    match direction {
+        Direction::Up => "U",
+        Direction::UpRight => "UR",
+        Direction::Right => "R",
+        Direction::DownRight => "DR",
+        Direction::Down => "D",
+        Direction::DownLeft => "DL",
+        Direction::Left => "L",
+        Direction::UpLeft => "UL",
+    }
+}
+
+// besides the short names, the full names are accepted in any case and with or without underscores, like UP_RIGHT or UpRight
+pub fn direction_from_name(name: &str) -> Option<Direction> {
+    let full = name.replace('_', "").to_lowercase();
+
+    Direction::all_directions().into_iter().find(|d| {
+        direction_name(*d).eq_ignore_ascii_case(name) || format!("{:?}", d).to_lowercase() == full
+    })
+}
+
+// fields outside of the board have no name
+pub fn field_name(position: &Coordinate) -> Option<String> {
+    if !(0..WIDTH as isize).contains(&position.x) || !(0..HEIGHT as isize).contains(&position.y) {
+        return None;
+    }
+
+    Some(format!("{}{}", (b'a' + position.x as u8) as char, position.y + 1))
+}
+
+pub fn parse_field_name(text: &str, column: usize) -> Result<Coordinate, NotationError> {
+    let invalid = || NotationError::new(format!("'{}' is not a field from a1 to j10", text), column);
+
+    let mut chars = text.chars();
+    let file = chars.next().ok_or_else(invalid)?.to_ascii_lowercase();
+    let rank = chars.as_str();
+
+    // usize::from_str would also take a leading '+'
+    if rank.is_empty() || !rank.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let rank: usize = rank.parse().map_err(|_| invalid())?;
+
+    if !('a'..='j').contains(&file) || !(1..=HEIGHT).contains(&rank) {
+        return Err(invalid());
+    }
+
+    Ok(Coordinate { x: (file as u8 - b'a') as isize, y: rank as isize - 1 })
+}
+
+// fields outside of the board have no name, so such moves are not written as they could not be read back
+pub fn move_to_notation(move_: &Move) -> Result<String, MoveError> {
+    let start = field_name(&move_.start).ok_or(MoveError::StartOutOfBounds { start: move_.start })?;
+    Ok(format!("{}>{}", start, direction_name(move_.direction)))
+}
+
+// the long form adds the target on the given board, like a4>R=c4
+pub fn move_to_long_notation(move_: &Move, board: &Board) -> Result<String, MoveError> {
+    let target = RulesEngine::target_position(board, move_);
+    let target_name = field_name(&target).ok_or(MoveError::TargetOutOfBounds { start: move_.start, target })?;
+    Ok(format!("{}={}", move_to_notation(move_)?, target_name))
+}
+
+// reads a4>R, a4>UR=c6 or A4 UP_RIGHT, the target of the long form is checked against the board if there is one
+pub fn parse_move(text: &str, column: usize, board: Option<&Board>) -> Result<Move, NotationError> {
+    let column = column + text.chars().take_while(|c| c.is_whitespace()).count();
+    let text = text.trim();
+    let column_of = |part: &str| column + text[..text.len() - part.len()].chars().count();
+
+    let (start, rest) = text.split_once('>')
+        .or_else(|| text.split_once(char::is_whitespace))
+        .ok_or_else(|| NotationError::new(format!("'{}' is not a move like a4>R, a4>R=c4 or A4 RIGHT", text), column))?;
+    let rest = rest.trim_start();
+    let (direction, target) = match rest.split_once('=') {
+        Some((direction, target)) => (direction, Some(target)),
+        None => (rest, None),
+    };
+
+    let move_ = Move {
+        start: parse_field_name(start, column)?,
+        direction: direction_from_name(direction)
+            .ok_or_else(|| NotationError::new(format!("'{}' is not one of the directions U, UR, R, DR, D, DL, L, UL", direction), column_of(rest)))?,
+    };
+
+    if let Some(target) = target {
+        let target_column = column_of(target);
+        let target_position = parse_field_name(target, target_column)?;
+
+        let difference = move_.start.get_difference(&target_position);
+        let steps = difference.delta_x.abs().max(difference.delta_y.abs());
+        let in_direction = steps > 0 && move_.direction.to_vector().scale(steps) == difference;
+        let on_board = board.is_none_or(|b| RulesEngine::target_position(b, &move_) == target_position);
+
+        if !in_direction || !on_board {
+            let name = move_to_notation(&move_).expect("start was read from a field name");
+            return Err(NotationError::new(format!("{} is not the target of {}", target, name), target_column));
+        }
+    }
+
+    Ok(move_)
+}
+
+pub fn board_to_notation(board: &Board) -> String {
+    let rows: Vec<String> = (0..HEIGHT).rev()
+        .map(|y| {
+            let mut row = String::new();
+            let mut empty = 0;
+
+            for x in 0..WIDTH {
+                match board.get_field(&Coordinate { x: x as isize, y: y as isize }).and_then(field_char) {
+                    Some(c) => {
+                        if empty > 0 {
+                            row.push_str(&empty.to_string());
+                            empty = 0;
+                        }
+                        row.push(c);
+                    },
+                    None => empty += 1,
+                }
+            }
+            if empty > 0 {
+                row.push_str(&empty.to_string());
+            }
+
+            row
+        })
+        .collect();
+
+    rows.join("/")
+}
+
+pub fn parse_board(text: &str, column: usize) -> Result<Board, NotationError> {
+    let mut board = Board::empty();
+
+    let rows: Vec<&str> = text.split('/').collect();
+    if rows.len() != HEIGHT {
+        return Err(NotationError::new(format!("Expected {} rows separated by '/', found {}", HEIGHT, rows.len()), column));
+    }
+
+    let mut row_column = column;
+    for (row, y) in rows.iter().zip((0..HEIGHT).rev()) {
+        let mut x = 0;
+        let mut empty = String::new();
+
+        for (offset, c) in row.chars().enumerate() {
+            let c_column = row_column + offset;
+
+            if c.is_ascii_digit() {
+                empty.push(c);
+                continue;
+            }
+            x += take_empty(&mut empty, c_column)?;
+
+            let field = field_from_char(c)
+                .ok_or_else(|| NotationError::new(format!("'{}' is neither a fish (S, M, L, s, m, l), a squid (*) nor a number of empty fields", c), c_column))?;
+            if x >= WIDTH {
+                return Err(NotationError::new(format!("Row {} has more than {} fields", y + 1, WIDTH), c_column));
+            }
+
+            board.set_field(&Coordinate { x: x as isize, y: y as isize }, field);
+            x += 1;
+        }
+        x += take_empty(&mut empty, row_column + row.chars().count())?;
+
+        if x != WIDTH {
+            return Err(NotationError::new(format!("Row {} has {} fields instead of {}", y + 1, x, WIDTH), row_column));
+        }
+        row_column += row.chars().count() + 1;
+    }
+
+    Ok(board)
+}
+
+// the number of empty fields collected from the digits before the given column
+fn take_empty(digits: &mut String, column: usize) -> Result<usize, NotationError> {
+    if digits.is_empty() {
+        return Ok(0);
+    }
+
+    let start = column - digits.chars().count();
+    let count: usize = digits.parse().map_err(|_| NotationError::new("Number of empty fields is too large", start))?;
+    if count == 0 || count > WIDTH {
+        return Err(NotationError::new(format!("{} is not a number of empty fields from 1 to {}", digits, WIDTH), start));
+    }
+
+    digits.clear();
+    Ok(count)
+}
+
+pub fn state_to_notation(state: &GameState) -> Result<String, MoveError> {
+    let last_move = state.last_move.as_ref().map(move_to_notation).transpose()?.unwrap_or_else(|| "-".to_string());
+    Ok(format!("{} {} {}", board_to_notation(&state.board), state.turn, last_move))
+}
+
+// the turn and the last move can be left out for the start of a game
+pub fn parse_state(text: &str) -> Result<GameState, NotationError> {
+    let parts = split_whitespace_with_columns(text);
+
+    let (board, board_column) = match parts.first() {
+        Some((column, board)) => (*board, *column),
+        None => return Err(NotationError::new("Notation is empty", 0)),
+    };
+    if parts.len() > 3 {
+        return Err(NotationError::new("Expected at most the board, the turn and the last move", parts[3].0));
+    }
+
+    let board = parse_board(board, board_column)?;
+
+    let turn = match parts.get(1) {
+        Some((column, turn)) => turn.parse()
+            .map_err(|_| NotationError::new(format!("'{}' is not a turn", turn), *column))?,
+        None => 0,
+    };
+
+    let last_move = match parts.get(2) {
+        Some((_, "-")) | None => None,
+        Some((column, last_move)) => Some(parse_move(last_move, *column, None)?),
+    };
+
+    Ok(GameState::new(board, turn, last_move))
+}
+
+fn split_whitespace_with_columns(text: &str) -> Vec<(usize, &str)> {
+    let mut parts = vec![];
+    let mut start: Option<(usize, usize)> = None;
+
+    for (column, (index, c)) in text.char_indices().enumerate() {
+        match (c.is_whitespace(), start) {
+            (false, None) => start = Some((column, index)),
+            (true, Some((start_column, start_index))) => {
+                parts.push((start_column, &text[start_index..index]));
+                start = None;
+            },
+            _ => {},
+        }
+    }
+    if let Some((start_column, start_index)) = start {
+        parts.push((start_column, &text[start_index..]));
+    }
+
+    parts
+}
@@ -0,0 +1,156 @@
+use std::borrow::Cow;
+
+use pyo3::*;
+
+use crate::plugin2026::{
+    errors::XmlError,
+    game_state::GameState,
+    r#move::Move,
+    utils::{binary, hashing},
+    xml_codec::{Element, RoomMessage, ServerResult, XmlCodec}
+};
+
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Replay {
+    // one state per turn, in the order they were played
+    #[pyo3(get)]
+    pub states: Vec<GameState>,
+    #[pyo3(get)]
+    pub result: Option<ServerResult>,
+}
+
+#[pymethods]
+impl Replay {
+    #[new]
+    #[pyo3(signature = (states, result=None))]
+    pub fn new(states: Vec<GameState>, result: Option<ServerResult>) -> Self {
+        Self { states, result }
+    }
+
+    #[staticmethod]
+    pub fn from_xml(xml: &str) -> Result<Replay, XmlError> {
+        let mut replay = Replay::new(vec![], None);
+
+        for element in Element::parse(xml)? {
+            replay.read(&element)?;
+        }
+
+        Ok(replay)
+    }
+
+    #[staticmethod]
+    pub fn from_file(path: &str) -> PyResult<Replay> {
+        let xml = std::fs::read_to_string(path)?;
+        Ok(Self::from_xml(&xml)?)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Replay {{ states: {}, result: {:?} }}", self.states.len(), self.result)
+    }
+    fn __eq__(&self, other: &Replay) -> bool {self == other}
+    fn __ne__(&self, other: &Replay) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __copy__(&self) -> Replay {self.clone()}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Replay {self.clone()}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
+    fn __len__(&self) -> usize {self.states.len()}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Replay> {
+        binary::from_bytes(data)
+    }
+
+    // negative indices count from the end, like in python lists
+    fn __getitem__(&self, index: isize) -> PyResult<GameState> {
+        let resolved = if index < 0 { index + self.states.len() as isize } else { index };
+
+        usize::try_from(resolved).ok()
+            .and_then(|i| self.states.get(i))
+            .cloned()
+            .ok_or_else(|| exceptions::PyIndexError::new_err("Replay index out of range"))
+    }
+
+    fn __iter__(&self) -> ReplayIterator {
+        ReplayIterator { states: self.states.clone().into_iter() }
+    }
+
+    // every state with the move that leads to the next one, None for the final state
+    // and where the next state does not directly follow, e.g. after a skipped turn or a gap in the log
+    pub fn moves(&self) -> Vec<(GameState, Option<Move>)> {
+        self.states.iter()
+            .enumerate()
+            .map(|(i, state)| {
+                let move_ = self.states.get(i + 1)
+                    .and_then(|next| next.last_move.clone().filter(|m| state.perform_move(m).is_ok_and(|s| s == *next)));
+                (state.clone(), move_)
+            })
+            .collect()
+    }
+
+    pub fn state_at(&self, turn: usize) -> Option<GameState> {
+        self.states.iter().find(|s| s.turn == turn).cloned()
+    }
+
+    pub fn final_state(&self) -> Option<GameState> {
+        self.states.last().cloned()
+    }
+}
+
+// rust exclusive methods
+impl Replay {
+    fn read(&mut self, element: &Element) -> Result<(), XmlError> {
+        match element.name.as_str() {
+            "room" => match XmlCodec::message_from(element)?.1 {
+                RoomMessage::State(state) => self.push(state),
+                RoomMessage::Result(result) => self.result = Some(result),
+                RoomMessage::Move(_) | RoomMessage::Other(_) => {},
+            },
+            // server replays hold the states and the result without a room around them
+            "state" => self.push(XmlCodec::state_from(element)?),
+            "data" if element.attribute("class") == Some("result") => self.result = Some(XmlCodec::result_from(element)?),
+            _ => {
+                for child in &element.children {
+                    self.read(child)?;
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    // both players receive every state, so a merged log contains each turn twice
+    fn push(&mut self, state: GameState) {
+        if self.states.last().is_none_or(|last| last.turn != state.turn) {
+            self.states.push(state);
+        }
+    }
+}
+
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone)]
+pub struct ReplayIterator {
+    pub states: std::vec::IntoIter<GameState>,
+}
+
+#[pymethods]
+impl ReplayIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {slf}
+    fn __next__(&mut self) -> Option<GameState> {self.states.next()}
+    fn __copy__(&self) -> ReplayIterator {self.clone()}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> ReplayIterator {self.clone()}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<ReplayIterator> {
+        binary::from_bytes(data)
+    }
+}
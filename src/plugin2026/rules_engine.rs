@@ -1,18 +1,20 @@
-use std::vec;
+use std::{cmp::Ordering, vec};
 
 use pyo3::*;
 
 use crate::plugin2026::{
-    board::Board, errors::PiranhasError, field_type::FieldType, r#move::Move,
+    board::Board, errors::MoveError, field_type::FieldType, r#move::Move, swarm::Swarm,
     utils::{
         constants::PluginConstants,
+        bitboard,
         coordinate::Coordinate,
         direction::Direction,
         team::TeamEnum
     }
 };
 
-#[pyclass]
+// has no state and no instances, pickle refers to the class by name
+#[pyclass(module = "socha._socha")]
 pub struct RulesEngine;
 
 #[pymethods]
@@ -20,7 +22,7 @@ impl RulesEngine {
 
     #[staticmethod]
     pub fn move_distance(board: &Board, move_: &Move) -> usize {
-        board.get_fish_on_line(&move_.start, &move_.direction).len()
+        board.fish_count_on_line(&move_.start, &move_.direction)
     }
 
     #[staticmethod]
@@ -35,45 +37,50 @@ impl RulesEngine {
     }
 
     #[staticmethod]
-    pub fn can_execute_move(board: &Board, move_: &Move) -> Result<(), PyErr> {
+    pub fn can_execute_move(board: &Board, move_: &Move) -> Result<(), MoveError> {
 
-        let target_pos = Self::target_position(board, move_);
+        let start = move_.start;
+        let target = Self::target_position(board, move_);
 
-        Self::is_in_bounds(&move_.start)
-            .then_some(())
-            .ok_or_else(|| PiranhasError::new_err("Start position is out of bounds"))?;
+        if !Self::is_in_bounds(&start) {
+            return Err(MoveError::StartOutOfBounds { start });
+        }
 
-        Self::is_in_bounds(&target_pos)
-            .then_some(())
-            .ok_or_else(|| PiranhasError::new_err("Target position is out of bounds"))?;
+        if !Self::is_in_bounds(&target) {
+            return Err(MoveError::TargetOutOfBounds { start, target });
+        }
 
-        let start_field = board.get_field(&move_.start)
+        let start_field = board.get_field(&start)
             .expect("Already validated in-bounds position");
-        let target_field = board.get_field(&target_pos)
+        let target_field = board.get_field(&target)
             .expect("Already validated in-bounds position");
 
         let this_team = start_field
             .get_team()
-            .ok_or_else(|| PiranhasError::new_err("Start position is not on fish field"))?;
-
-        let mut blocked_fields = this_team.get_fish_types();
-        blocked_fields.push(FieldType::Squid);
+            .ok_or(MoveError::NotAFish { start })?;
 
-        if blocked_fields.contains(&target_field) {
-            return Err(PiranhasError::new_err("Cannot swim onto field of own team or squid"));
+        if target_field == FieldType::Squid {
+            return Err(MoveError::BlockedBySquid { start, target });
         }
 
-        let distance = Self::move_distance(board, move_);
-        let direction_fields = board.get_fields_in_direction(&move_.start, &move_.direction);
-        let path_fields: Vec<_> = direction_fields.iter().take(distance - 1).cloned().collect(); // not including start or target
-
-        for d in path_fields {
-            let mut blocked_fields = this_team.get_fish_types();
-            blocked_fields.push(FieldType::Squid);
+        if this_team.get_fish_types().contains(&target_field) {
+            return Err(MoveError::OwnFishOnTarget { start, target });
+        }
 
-            if this_team.opponent().get_fish_types().contains(&d) {
-                return Err(PiranhasError::new_err("Cannot swim over other team's fish"));
-            }
+        // not including start or target
+        let start_index = bitboard::index_of(&start).expect("Already validated in-bounds position");
+        let target_index = bitboard::index_of(&target).expect("Already validated in-bounds position");
+        let path = bitboard::ray(start_index, &move_.direction)
+            & !bitboard::ray(target_index, &move_.direction)
+            & !(1 << target_index);
+
+        if path & board.team_mask(&this_team.opponent()) != 0 {
+            // report the first fish in the way
+            let over = (1..Self::move_distance(board, move_))
+                .map(|i| start.add_vector(&move_.direction.to_vector().scale(i as isize)))
+                .find(|c| board.get_field(c).and_then(|f| f.get_team()) == Some(this_team.opponent()))
+                .expect("Path contains a fish of the other team");
+            return Err(MoveError::JumpsOverOpponent { start, target, over });
         }
 
         Ok(())
@@ -91,66 +98,84 @@ impl RulesEngine {
     #[staticmethod]
     pub fn swarm_from(board: &Board, position: &Coordinate) -> Vec<Coordinate> {
 
-        if !RulesEngine::is_in_bounds(position) {
-            return vec![];
-        }
-        
-        let Some(this_team) = board.get_field(position).unwrap().get_team() else {
+        let Some(this_team) = board.get_field(position).and_then(|f| f.get_team()) else {
             return vec![];
         };
 
-        let mut todo: Vec<Coordinate> = vec![position.to_owned()];
-        let mut visited: Vec<Coordinate> = Vec::new();
+        let swarm = bitboard::flood_fill(bitboard::bit_of(position), board.team_mask(&this_team));
+        bitboard::coordinates(swarm).collect()
+    }
 
-        while !todo.is_empty() {
+    #[staticmethod]
+    pub fn swarms_of_team(board: &Board, team: &TeamEnum) -> Vec<Vec<Coordinate>> {
 
-            let neighbors = RulesEngine::valid_neighbors(&todo[0]);
-            for n in neighbors {
-                if visited.contains(&n) || todo.contains(&n) {
-                    continue;
-                }
+        let mut team_fish = board.team_mask(team);
 
-                if let Some(team) = board.get_field(&n).unwrap().get_team() {
-                    if team == this_team {
-                        todo.push(n)
-                    }
-                }
-            }
+        let mut swarms: Vec<Vec<Coordinate>> = Vec::new();
+        while team_fish != 0 {
+            let seed = team_fish & team_fish.wrapping_neg();
+            let swarm = bitboard::flood_fill(seed, team_fish);
 
-            visited.push(todo[0]);
-            todo.remove(0);
+            team_fish &= !swarm;
+            swarms.push(bitboard::coordinates(swarm).collect())
         }
 
-        visited
+        swarms
     }
 
     #[staticmethod]
-    pub fn swarms_of_team(board: &Board, team: &TeamEnum) -> Vec<Vec<Coordinate>> {
-
-        let mut team_fish: Vec<Coordinate> = Vec::new();
-        for f in team.get_fish_types() {
-            team_fish.extend(board.get_fields_by_type(f));
-        }
+    pub fn swarm_values(board: &Board, team: &TeamEnum) -> Vec<Swarm> {
+        let mut swarms: Vec<Swarm> = RulesEngine::swarms_of_team(board, team)
+            .into_iter()
+            .map(|members| Swarm::new(board, *team, members))
+            .collect();
 
-        let mut swarms: Vec<Vec<Coordinate>> = Vec::new();
-        while !team_fish.is_empty() {
-            let visited = RulesEngine::swarm_from(board, &team_fish[0]);
+        swarms.sort_by(|a, b| b.value.cmp(&a.value).then(b.size().cmp(&a.size())));
+        swarms
+    }
 
-            for v in &visited {
-                if let Some(index) = team_fish.iter().position(|x| x == v) {
-                    team_fish.remove(index);
-                }
-            }
+    #[staticmethod]
+    pub fn largest_swarm_value(board: &Board, team: &TeamEnum) -> usize {
+        RulesEngine::swarms_of_team(board, team)
+            .iter()
+            .map(|s| RulesEngine::swarm_value(board, s))
+            .max()
+            .unwrap_or(0)
+    }
 
-            swarms.push(visited)
+    #[staticmethod]
+    pub fn compare_swarms(board: &Board) -> Option<TeamEnum> {
+        match RulesEngine::swarm_ordering(board) {
+            Ordering::Greater => Some(TeamEnum::One),
+            Ordering::Less => Some(TeamEnum::Two),
+            Ordering::Equal => None,
         }
+    }
 
-        swarms
+    #[staticmethod]
+    pub fn is_swarm_connected(board: &Board, team: &TeamEnum) -> bool {
+        RulesEngine::swarms_of_team(board, team).len() == 1
     }
 }
 
 // rust exclusive methods
 impl RulesEngine {
+    pub fn swarm_value(board: &Board, swarm: &[Coordinate]) -> usize {
+        swarm.iter()
+            .filter_map(|c| board.get_field(c))
+            .map(|f| f.get_value())
+            .sum()
+    }
+
+    // the largest swarm decides, ties fall through to its size and then to the next swarms
+    pub fn swarm_ordering(board: &Board) -> Ordering {
+        let ranking = |team: &TeamEnum| -> Vec<(usize, usize)> {
+            RulesEngine::swarm_values(board, team).iter().map(|s| (s.value, s.size())).collect()
+        };
+
+        ranking(&TeamEnum::One).cmp(&ranking(&TeamEnum::Two))
+    }
+
     pub fn valid_neighbors(position: &Coordinate) -> Vec<Coordinate> {
 
         let mut coordinates: Vec<Coordinate> = Vec::new();
@@ -0,0 +1,4 @@
+pub mod evaluator;
+pub mod mcts;
+pub mod searcher;
+pub mod transposition_table;
\ No newline at end of file
@@ -0,0 +1,199 @@
+use std::borrow::Cow;
+
+use pyo3::{prelude::PyAnyMethods, *};
+
+use crate::plugin2026::{
+    game_state::GameState,
+    rules_engine::RulesEngine,
+    search::searcher::MAX_EVALUATION,
+    utils::{
+        binary,
+        bitboard,
+        coordinate::Coordinate,
+        hashing,
+        team::TeamEnum
+    }
+};
+
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Evaluator {
+    #[pyo3(get, set)]
+    pub swarm_size: f64,
+    #[pyo3(get, set)]
+    pub largest_swarm_value: f64,
+    #[pyo3(get, set)]
+    pub swarm_count: f64,
+    #[pyo3(get, set)]
+    pub centre_distance: f64,
+    #[pyo3(get, set)]
+    pub mobility: f64,
+    #[pyo3(get, set)]
+    pub squid_adjacency: f64,
+}
+
+#[pymethods]
+impl Evaluator {
+    #[new]
+    #[pyo3(signature = (
+        swarm_size=1.0,
+        largest_swarm_value=1.0,
+        swarm_count=-2.0,
+        centre_distance=-1.0,
+        mobility=0.0,
+        squid_adjacency=0.0
+    ))]
+    pub fn new(
+        swarm_size: f64,
+        largest_swarm_value: f64,
+        swarm_count: f64,
+        centre_distance: f64,
+        mobility: f64,
+        squid_adjacency: f64
+    ) -> Self {
+        Self {
+            swarm_size,
+            largest_swarm_value,
+            swarm_count,
+            centre_distance,
+            mobility,
+            squid_adjacency,
+        }
+    }
+
+    fn __repr__(&self) -> String {format!("{:?}", self)}
+    fn __eq__(&self, other: &Evaluator) -> bool {self == other}
+    fn __ne__(&self, other: &Evaluator) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __copy__(&self) -> Evaluator {self.clone()}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Evaluator {self.clone()}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
+    fn deepcopy(&self) -> Evaluator {self.clone()}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Evaluator> {
+        binary::from_bytes(data)
+    }
+
+    pub fn evaluate(&self, state: &GameState) -> f64 {
+        let team = RulesEngine::get_team_on_turn(state.turn);
+
+        self.features(state, &team)
+            .iter()
+            .zip(self.features(state, &team.opponent()).iter())
+            .zip(self.weights().iter())
+            .map(|((own, other), w)| w * (own - other))
+            .sum()
+    }
+}
+
+impl std::hash::Hash for Evaluator {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.weights().map(hashing::float_bits).hash(state);
+    }
+}
+
+impl Default for Evaluator {
+    fn default() -> Self {
+        Self::new(1.0, 1.0, -2.0, -1.0, 0.0, 0.0)
+    }
+}
+
+// rust exclusive methods
+impl Evaluator {
+    fn weights(&self) -> [f64; 6] {
+        [
+            self.swarm_size,
+            self.largest_swarm_value,
+            self.swarm_count,
+            self.centre_distance,
+            self.mobility,
+            self.squid_adjacency,
+        ]
+    }
+
+    // the raw feature values of one team, in the same order as the weights
+    fn features(&self, state: &GameState, team: &TeamEnum) -> [f64; 6] {
+        let swarms = RulesEngine::swarm_values(&state.board, team);
+        let largest = swarms.first();
+
+        let fish: Vec<Coordinate> = bitboard::coordinates(state.board.team_mask(team)).collect();
+        let centre_distance = if fish.is_empty() {
+            0.0
+        } else {
+            fish.iter()
+                .map(|c| ((c.x as f64 - 4.5).powi(2) + (c.y as f64 - 4.5).powi(2)).sqrt())
+                .sum::<f64>() / fish.len() as f64
+        };
+
+        // mobility is the most expensive feature, so it is skipped if it does not count
+        let mobility = if self.mobility != 0.0 {
+            let mut own_turn = state.clone();
+            if RulesEngine::get_team_on_turn(own_turn.turn) != *team {
+                own_turn.turn += 1;
+            }
+            own_turn.possible_moves().len() as f64
+        } else {
+            0.0
+        };
+
+        let squid_adjacency = (bitboard::dilate(state.board.squid_mask()) & state.board.team_mask(team)).count_ones() as f64;
+
+        [
+            largest.map_or(0.0, |s| s.size() as f64),
+            largest.map_or(0.0, |s| s.value as f64),
+            swarms.len() as f64,
+            centre_distance,
+            mobility,
+            squid_adjacency,
+        ]
+    }
+}
+
+#[derive(Debug)]
+pub enum EvaluationFunction {
+    Native(Evaluator),
+    Python(PyObject),
+}
+
+impl EvaluationFunction {
+    pub fn from_py(evaluator: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        let Some(evaluator) = evaluator else {
+            return Ok(Self::Native(Evaluator::default()));
+        };
+
+        if let Ok(native) = evaluator.extract::<Evaluator>() {
+            Ok(Self::Native(native))
+        } else if evaluator.is_callable() {
+            Ok(Self::Python(evaluator.clone().unbind()))
+        } else {
+            Err(exceptions::PyTypeError::new_err("Evaluator must be an Evaluator or a callable"))
+        }
+    }
+
+    // scores are seen from the team on turn
+    pub fn evaluate(&self, state: &GameState) -> PyResult<f64> {
+        let score = match self {
+            Self::Native(evaluator) => evaluator.evaluate(state),
+            Self::Python(function) => Python::with_gil(|py| {
+                function.call1(py, (state.clone(),))?.extract::<f64>(py)
+            })?,
+        };
+
+        if !score.is_finite() {
+            return Err(exceptions::PyValueError::new_err(format!("Evaluation has to be a finite number, not {}", score)));
+        }
+
+        Ok(score.clamp(-MAX_EVALUATION, MAX_EVALUATION))
+    }
+}
+
+impl Default for EvaluationFunction {
+    fn default() -> Self {
+        Self::Native(Evaluator::default())
+    }
+}
@@ -0,0 +1,318 @@
+use std::{borrow::Cow, time::{Duration, Instant}};
+
+use pyo3::*;
+
+use crate::plugin2026::{
+    game_state::GameState,
+    r#move::Move,
+    rules_engine::RulesEngine,
+    search::evaluator::EvaluationFunction,
+    utils::{
+        binary,
+        hashing,
+        random::Random,
+        team::TeamEnum
+    }
+};
+
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Playout {
+    Random,
+    Heuristic
+}
+
+#[pymethods]
+impl Playout {
+    fn __str__(&self) -> String {format!("{:?}", self)}
+    fn __repr__(&self) -> String {format!("{:?}", self)}
+    fn __eq__(&self, other: &Playout) -> bool {self == other}
+    fn __ne__(&self, other: &Playout) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __copy__(&self) -> Playout {*self}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Playout {*self}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Playout> {
+        binary::from_bytes(data)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    state: GameState,
+    move_: Option<Move>,
+    children: Vec<usize>,
+    untried: Vec<Move>,
+    visits: u64,
+    // summed rewards of the team that made the move leading to this node
+    reward: f64,
+}
+
+impl Node {
+    fn new(state: GameState, move_: Option<Move>) -> Self {
+        let untried = if state.is_over() { vec![] } else { state.possible_moves() };
+
+        Self {
+            state,
+            move_,
+            children: vec![],
+            untried,
+            visits: 0,
+            reward: 0.0,
+        }
+    }
+}
+
+#[pyclass(module = "socha._socha")]
+#[derive(Debug)]
+pub struct MonteCarloSearcher {
+    #[pyo3(get, set)]
+    pub exploration: f64,
+    #[pyo3(get, set)]
+    pub playout: Playout,
+    // how many random moves are compared by the evaluator in each step of a heuristic playout
+    #[pyo3(get, set)]
+    pub playout_candidates: usize,
+    #[pyo3(get)]
+    pub iterations: u64,
+    pub evaluation: EvaluationFunction,
+    nodes: Vec<Node>,
+    random: Random,
+    error: Option<PyErr>,
+}
+
+#[pymethods]
+impl MonteCarloSearcher {
+    #[new]
+    #[pyo3(signature = (exploration=std::f64::consts::SQRT_2, playout=Playout::Random, seed=None, evaluator=None))]
+    fn py_new(exploration: f64, playout: Playout, seed: Option<u64>, evaluator: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        let random = seed.map_or_else(Random::from_time, Random::new);
+        Ok(Self::new(exploration, playout, random, EvaluationFunction::from_py(evaluator)?))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "MonteCarloSearcher {{ exploration: {}, playout: {:?}, iterations: {}, tree_size: {} }}",
+            self.exploration, self.playout, self.iterations, self.nodes.len()
+        )
+    }
+    fn __reduce__(&self) -> PyResult<()> {Err(binary::not_picklable::<MonteCarloSearcher>())}
+
+    #[pyo3(name = "search")]
+    #[pyo3(signature = (state, time_ms=None, iterations=None))]
+    fn py_search(&mut self, py: Python<'_>, state: &GameState, time_ms: Option<u64>, iterations: Option<u64>) -> PyResult<Option<(Move, f64, Vec<Move>)>> {
+        if time_ms.is_none() && iterations.is_none() {
+            return Err(exceptions::PyValueError::new_err("Either time_ms or iterations has to be given"));
+        }
+
+        let state = state.clone();
+        let result = py.allow_threads(|| self.search(&state, time_ms.map(Duration::from_millis), iterations));
+
+        match self.error.take() {
+            Some(error) => Err(error),
+            None => Ok(result),
+        }
+    }
+
+    pub fn tree_size(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+    }
+}
+
+// rust exclusive methods
+impl MonteCarloSearcher {
+    pub fn new(exploration: f64, playout: Playout, random: Random, evaluation: EvaluationFunction) -> Self {
+        Self {
+            exploration,
+            playout,
+            playout_candidates: 4,
+            iterations: 0,
+            evaluation,
+            nodes: vec![],
+            random,
+            error: None,
+        }
+    }
+
+    // searches until the time or the number of iterations is used up, whatever comes first
+    pub fn search(&mut self, state: &GameState, time: Option<Duration>, iterations: Option<u64>) -> Option<(Move, f64, Vec<Move>)> {
+        self.iterations = 0;
+        self.error = None;
+
+        if state.is_over() {
+            return None;
+        }
+
+        self.reuse_tree(state);
+        self.nodes[0].move_ = None;
+
+        let deadline = time.map(|t| Instant::now() + t);
+        // at least one iteration is needed to have a move at all
+        loop {
+            self.iterate();
+            self.iterations += 1;
+
+            if self.error.is_some() {
+                return None;
+            }
+
+            if iterations.is_some_and(|i| self.iterations >= i) || deadline.is_some_and(|d| Instant::now() >= d) {
+                break;
+            }
+        }
+
+        let mut pv: Vec<Move> = vec![];
+        let mut node = 0;
+        while let Some(&best) = self.nodes[node].children.iter().max_by_key(|&&c| self.nodes[c].visits) {
+            pv.push(self.nodes[best].move_.clone().expect("Only the root has no move"));
+            node = best;
+        }
+
+        let best = *self.nodes[0].children.iter().max_by_key(|&&c| self.nodes[c].visits)?;
+        let win_rate = self.nodes[best].reward / self.nodes[best].visits.max(1) as f64;
+
+        Some((pv[0].clone(), win_rate, pv))
+    }
+
+    // keeps the subtree of the given state if it follows from the last searched position by one or two moves
+    fn reuse_tree(&mut self, state: &GameState) {
+        let found = self.nodes.first().and_then(|root| {
+            root.children.iter()
+                .flat_map(|&c| std::iter::once(c).chain(self.nodes[c].children.iter().copied()))
+                .find(|&n| self.nodes[n].state.last_move == state.last_move && self.nodes[n].state == *state)
+        });
+
+        let Some(found) = found else {
+            self.nodes = vec![Node::new(state.clone(), None)];
+            return;
+        };
+
+        // move the subtree into a fresh arena, so that the rest of the old tree is dropped
+        let mut old: Vec<Option<Node>> = std::mem::take(&mut self.nodes).into_iter().map(Some).collect();
+        let mut todo: Vec<(usize, Option<usize>)> = vec![(found, None)];
+        while let Some((index, parent)) = todo.pop() {
+            let mut node = old[index].take().expect("Every node has one parent");
+            let children = std::mem::take(&mut node.children);
+
+            let new_index = self.nodes.len();
+            self.nodes.push(node);
+            if let Some(p) = parent {
+                self.nodes[p].children.push(new_index);
+            }
+
+            todo.extend(children.into_iter().map(|c| (c, Some(new_index))));
+        }
+    }
+
+    fn iterate(&mut self) {
+        // selection
+        let mut path: Vec<usize> = vec![0];
+        let mut node = 0;
+        while self.nodes[node].untried.is_empty() && !self.nodes[node].children.is_empty() {
+            node = self.select_child(node);
+            path.push(node);
+        }
+
+        // expansion
+        if !self.nodes[node].untried.is_empty() {
+            let index = self.random.below(self.nodes[node].untried.len());
+            let move_ = self.nodes[node].untried.swap_remove(index);
+            let state = self.nodes[node].state.perform_move(&move_).expect("Generated moves are valid");
+
+            let child = self.nodes.len();
+            self.nodes.push(Node::new(state, Some(move_)));
+            self.nodes[node].children.push(child);
+
+            node = child;
+            path.push(child);
+        }
+
+        // simulation
+        let winner = self.simulate(self.nodes[node].state.clone());
+
+        // backpropagation
+        for &n in &path {
+            let node = &mut self.nodes[n];
+            let mover = RulesEngine::get_team_on_turn(node.state.turn).opponent();
+
+            node.visits += 1;
+            node.reward += match winner {
+                Some(team) if team == mover => 1.0,
+                Some(_) => 0.0,
+                None => 0.5,
+            };
+        }
+    }
+
+    fn select_child(&self, node: usize) -> usize {
+        let parent_visits = (self.nodes[node].visits.max(1) as f64).ln();
+
+        let uct = |c: usize| {
+            let child = &self.nodes[c];
+            let visits = child.visits.max(1) as f64;
+            child.reward / visits + self.exploration * (parent_visits / visits).sqrt()
+        };
+
+        *self.nodes[node].children.iter()
+            .max_by(|&&a, &&b| uct(a).total_cmp(&uct(b)))
+            .expect("Node has children")
+    }
+
+    // plays until the game is over, which at the latest happens at the round limit
+    fn simulate(&mut self, mut state: GameState) -> Option<TeamEnum> {
+        loop {
+            if let Some(result) = state.game_result() {
+                return result.winner;
+            }
+
+            let moves = state.possible_moves();
+            if moves.is_empty() {
+                return None;
+            }
+
+            let move_ = match self.playout {
+                Playout::Random => self.random.choose(&moves).expect("Moves are not empty").clone(),
+                Playout::Heuristic => self.heuristic_move(&mut state, &moves)?,
+            };
+
+            state.perform_move_mut(&move_).expect("Generated moves are valid");
+        }
+    }
+
+    fn heuristic_move(&mut self, state: &mut GameState, moves: &[Move]) -> Option<Move> {
+        let mut best: Option<(Move, f64)> = None;
+
+        for _ in 0..self.playout_candidates.max(1) {
+            let candidate = self.random.choose(moves).expect("Moves are not empty").clone();
+            let token = state.make_move(&candidate).expect("Generated moves are valid");
+
+            // the evaluation is seen from the opponent after the move
+            let evaluation = self.evaluation.evaluate(state);
+            state.unmake_move(&token).expect("Moves are taken back in reverse order");
+
+            let score = match evaluation {
+                Ok(score) => -score,
+                Err(error) => {
+                    self.error = Some(error);
+                    return None;
+                }
+            };
+
+            if best.as_ref().is_none_or(|(_, s)| score > *s) {
+                best = Some((candidate, score));
+            }
+        }
+
+        best.map(|(m, _)| m)
+    }
+}
@@ -0,0 +1,283 @@
+use std::time::{Duration, Instant};
+
+use pyo3::*;
+
+use crate::plugin2026::{
+    game_state::GameState,
+    r#move::Move,
+    rules_engine::RulesEngine,
+    search::{
+        evaluator::EvaluationFunction,
+        transposition_table::{BoundType, TableEntry, TranspositionTable}
+    },
+    utils::binary
+};
+
+pub const WIN_SCORE: f64 = 1_000_000.0;
+
+// scores this close to WIN_SCORE are wins found in the search
+const MAX_PLY: f64 = 1_000.0;
+
+// evaluations are kept below the scores of wins, so a good position is not taken for a forced win
+pub const MAX_EVALUATION: f64 = WIN_SCORE - MAX_PLY - 1.0;
+
+// how many nodes are searched between two looks at the clock
+const TIME_CHECK_INTERVAL: u64 = 1024;
+
+#[pyclass(module = "socha._socha")]
+#[derive(Debug)]
+pub struct Searcher {
+    #[pyo3(get, set)]
+    pub max_depth: usize,
+    #[pyo3(get)]
+    pub nodes: u64,
+    #[pyo3(get)]
+    pub depth: usize,
+    pub evaluation: EvaluationFunction,
+    pub table: TranspositionTable,
+    deadline: Option<Instant>,
+    aborted: bool,
+    error: Option<PyErr>,
+}
+
+#[pymethods]
+impl Searcher {
+    #[new]
+    #[pyo3(signature = (max_depth=64, evaluator=None, table_size=TranspositionTable::DEFAULT_SIZE))]
+    fn py_new(max_depth: usize, evaluator: Option<&Bound<'_, PyAny>>, table_size: usize) -> PyResult<Self> {
+        let mut searcher = Self::new(max_depth, EvaluationFunction::from_py(evaluator)?);
+        searcher.table = TranspositionTable::new(table_size);
+        Ok(searcher)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Searcher {{ max_depth: {}, nodes: {}, depth: {}, table: {:?} }}", self.max_depth, self.nodes, self.depth, self.table)
+    }
+    fn __reduce__(&self) -> PyResult<()> {Err(binary::not_picklable::<Searcher>())}
+
+    #[getter]
+    fn table(&self) -> TranspositionTable {
+        self.table.clone()
+    }
+
+    pub fn clear_table(&mut self) {
+        self.table.clear();
+    }
+
+    #[pyo3(name = "search")]
+    fn py_search(&mut self, py: Python<'_>, state: &GameState, time_ms: u64) -> PyResult<Option<(Move, f64, Vec<Move>)>> {
+        let state = state.clone();
+        let result = py.allow_threads(|| self.search(&state, Duration::from_millis(time_ms)));
+
+        match self.error.take() {
+            Some(error) => Err(error),
+            None => Ok(result),
+        }
+    }
+}
+
+// rust exclusive methods
+impl Searcher {
+    pub fn new(max_depth: usize, evaluation: EvaluationFunction) -> Self {
+        Self {
+            max_depth,
+            nodes: 0,
+            depth: 0,
+            evaluation,
+            table: TranspositionTable::new(TranspositionTable::DEFAULT_SIZE),
+            deadline: None,
+            aborted: false,
+            error: None,
+        }
+    }
+
+    pub fn search(&mut self, state: &GameState, time: Duration) -> Option<(Move, f64, Vec<Move>)> {
+        self.run(state, time, false)
+    }
+
+    // like search, but even the first iteration stops at the deadline, so that a time limit is never exceeded
+    // if it does not finish, the first legal move stands in
+    pub fn search_within(&mut self, state: &GameState, time: Duration) -> Option<(Move, f64, Vec<Move>)> {
+        self.run(state, time, true)
+    }
+
+    fn run(&mut self, state: &GameState, time: Duration, bound_first_iteration: bool) -> Option<(Move, f64, Vec<Move>)> {
+        self.nodes = 0;
+        self.depth = 0;
+        let deadline = Instant::now() + time;
+        self.aborted = false;
+        self.error = None;
+
+        if state.is_over() {
+            return None;
+        }
+
+        let moves = state.possible_moves();
+        let first = moves.first()?;
+        let mut best: (Move, f64, Vec<Move>) = (first.clone(), -WIN_SCORE, vec![first.clone()]);
+
+        // moves are made and taken back on this copy, so the search itself does not allocate states
+        let mut root = state.clone();
+
+        for depth in 1..=self.max_depth {
+            // without a bound, the first iteration always finishes, so even without time there is a scored move and a line
+            self.deadline = (depth > 1 || bound_first_iteration).then_some(deadline);
+
+            let mut pv: Vec<Move> = best.2.clone();
+            let score = self.negamax(&mut root, depth, 0, -WIN_SCORE - 1.0, WIN_SCORE + 1.0, &mut pv);
+
+            // an aborted iteration is incomplete, so the previous one is kept
+            if self.aborted {
+                break;
+            }
+
+            best = (pv[0].clone(), score, pv);
+            self.depth = depth;
+
+            // a forced win or loss cannot change with more depth
+            if score.abs() >= WIN_SCORE - MAX_PLY {
+                break;
+            }
+        }
+
+        Some(best)
+    }
+
+    // pv holds the expected line on entry (used for move ordering) and the found line on return
+    fn negamax(&mut self, state: &mut GameState, depth: usize, ply: usize, mut alpha: f64, beta: f64, pv: &mut Vec<Move>) -> f64 {
+        let original_alpha = alpha;
+        self.nodes += 1;
+        // the clock is also read before every move at the root, where a slow evaluator alone can use up the time
+        let check_time = ply <= 1 || self.nodes.is_multiple_of(TIME_CHECK_INTERVAL);
+        if check_time && self.deadline.is_some_and(|d| Instant::now() >= d) {
+            self.aborted = true;
+        }
+        if self.aborted {
+            return 0.0;
+        }
+
+        let team = RulesEngine::get_team_on_turn(state.turn);
+        if let Some(result) = state.game_result() {
+            pv.clear();
+            return match result.winner {
+                Some(winner) if winner == team => WIN_SCORE - ply as f64,
+                Some(_) => -WIN_SCORE + ply as f64,
+                None => 0.0,
+            };
+        }
+
+        let key = TranspositionTable::key_of(state);
+        let mut table_move: Option<Move> = None;
+        if let Some(entry) = self.table.probe_key(key) {
+            table_move = entry.best_move.clone();
+
+            // the root always searches, so that there is a full line
+            if ply > 0 && entry.depth >= depth {
+                let score = Self::score_from_table(entry.score, ply);
+                let cutoff = match entry.bound {
+                    BoundType::Exact => true,
+                    BoundType::Lower => score >= beta,
+                    BoundType::Upper => score <= alpha,
+                };
+
+                if cutoff {
+                    pv.clear();
+                    pv.extend(table_move);
+                    return score;
+                }
+            }
+        }
+
+        let mut moves = state.possible_moves();
+        if depth == 0 || moves.is_empty() {
+            pv.clear();
+            return match self.evaluation.evaluate(state) {
+                Ok(score) => score,
+                Err(error) => {
+                    // the error is handed to python once the search has stopped
+                    self.error = Some(error);
+                    self.aborted = true;
+                    0.0
+                }
+            };
+        }
+
+        // search the move of the expected line first, otherwise the best move from the table
+        let mut expected_line: Vec<Move> = vec![];
+        if let Some(index) = pv.first().and_then(|m| moves.iter().position(|x| x == m)) {
+            moves.swap(0, index);
+            expected_line = pv[1..].to_vec();
+        } else if let Some(index) = table_move.and_then(|m| moves.iter().position(|x| *x == m)) {
+            moves.swap(0, index);
+        }
+
+        // the first move stands until another one scores better, so the line is never empty, even if no score beats the start
+        let mut best_score = -WIN_SCORE - 1.0;
+        pv.clear();
+        pv.push(moves[0].clone());
+
+        for (i, move_) in moves.iter().enumerate() {
+            let token = state.make_move(move_).expect("Generated moves are valid");
+
+            let mut child_pv = if i == 0 { expected_line.clone() } else { vec![] };
+            let score = -self.negamax(state, depth - 1, ply + 1, -beta, -alpha, &mut child_pv);
+
+            state.unmake_move(&token).expect("Moves are taken back in reverse order");
+
+            if self.aborted {
+                return 0.0;
+            }
+
+            if score > best_score {
+                best_score = score;
+                pv.clear();
+                pv.push(move_.clone());
+                pv.extend(child_pv);
+            }
+
+            alpha = alpha.max(score);
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        let bound = if best_score <= original_alpha {
+            BoundType::Upper
+        } else if best_score >= beta {
+            BoundType::Lower
+        } else {
+            BoundType::Exact
+        };
+
+        self.table.store_key(TableEntry {
+            key,
+            bound,
+            depth,
+            score: Self::score_to_table(best_score, ply),
+            best_move: pv.first().cloned(),
+        });
+
+        best_score
+    }
+
+    // wins are stored relative to the stored position, so they stay correct when found at another ply
+    fn score_to_table(score: f64, ply: usize) -> f64 {
+        if score >= WIN_SCORE - MAX_PLY {
+            score + ply as f64
+        } else if score <= -WIN_SCORE + MAX_PLY {
+            score - ply as f64
+        } else {
+            score
+        }
+    }
+
+    fn score_from_table(score: f64, ply: usize) -> f64 {
+        if score >= WIN_SCORE - MAX_PLY {
+            score - ply as f64
+        } else if score <= -WIN_SCORE + MAX_PLY {
+            score + ply as f64
+        } else {
+            score
+        }
+    }
+}
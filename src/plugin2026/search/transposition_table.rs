@@ -0,0 +1,207 @@
+use std::borrow::Cow;
+
+use pyo3::*;
+
+use crate::plugin2026::{
+    game_state::GameState,
+    r#move::Move,
+    utils::{binary, hashing, zobrist}
+};
+
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoundType {
+    Exact,
+    Lower,
+    Upper
+}
+
+#[pymethods]
+impl BoundType {
+    fn __str__(&self) -> String {format!("{:?}", self)}
+    fn __repr__(&self) -> String {format!("{:?}", self)}
+    fn __eq__(&self, other: &BoundType) -> bool {self == other}
+    fn __ne__(&self, other: &BoundType) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __copy__(&self) -> BoundType {*self}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> BoundType {*self}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<BoundType> {
+        binary::from_bytes(data)
+    }
+}
+
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableEntry {
+    #[pyo3(get)]
+    pub key: u64,
+    #[pyo3(get)]
+    pub bound: BoundType,
+    #[pyo3(get)]
+    pub depth: usize,
+    #[pyo3(get)]
+    pub score: f64,
+    #[pyo3(get)]
+    pub best_move: Option<Move>,
+}
+
+#[pymethods]
+impl TableEntry {
+    fn __repr__(&self) -> String {format!("{:?}", self)}
+    fn __eq__(&self, other: &TableEntry) -> bool {self == other}
+    fn __ne__(&self, other: &TableEntry) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __copy__(&self) -> TableEntry {self.clone()}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> TableEntry {self.clone()}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<TableEntry> {
+        binary::from_bytes(data)
+    }
+}
+
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone)]
+pub struct TranspositionTable {
+    pub entries: Vec<Option<TableEntry>>,
+    #[pyo3(get)]
+    pub hits: u64,
+    #[pyo3(get)]
+    pub misses: u64,
+    // probes that found an entry of another position in their slot
+    #[pyo3(get)]
+    pub collisions: u64,
+    #[pyo3(get)]
+    pub stores: u64,
+}
+
+#[pymethods]
+impl TranspositionTable {
+    pub const DEFAULT_SIZE: usize = 1 << 16;
+
+    #[new]
+    #[pyo3(signature = (size=TranspositionTable::DEFAULT_SIZE))]
+    pub fn new(size: usize) -> Self {
+        Self {
+            entries: vec![None; size.max(1)],
+            hits: 0,
+            misses: 0,
+            collisions: 0,
+            stores: 0,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "TranspositionTable {{ size: {}, filled: {}, hits: {}, misses: {}, collisions: {}, stores: {} }}",
+            self.size(), self.filled(), self.hits, self.misses, self.collisions, self.stores
+        )
+    }
+
+    fn __len__(&self) -> usize {self.filled()}
+    fn __copy__(&self) -> TranspositionTable {self.clone()}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> TranspositionTable {self.clone()}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<TranspositionTable> {
+        binary::from_bytes(data)
+    }
+
+    pub fn size(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn filled(&self) -> usize {
+        self.entries.iter().filter(|e| e.is_some()).count()
+    }
+
+    pub fn probe(&mut self, state: &GameState) -> Option<TableEntry> {
+        self.probe_key(Self::key_of(state)).cloned()
+    }
+
+    #[pyo3(signature = (state, depth, score, bound, best_move=None))]
+    pub fn store(&mut self, state: &GameState, depth: usize, score: f64, bound: BoundType, best_move: Option<Move>) -> bool {
+        self.store_key(TableEntry { key: Self::key_of(state), bound, depth, score, best_move })
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.iter_mut().for_each(|e| *e = None);
+        self.hits = 0;
+        self.misses = 0;
+        self.collisions = 0;
+        self.stores = 0;
+    }
+}
+
+impl std::hash::Hash for TableEntry {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        (self.key, self.bound, self.depth, hashing::float_bits(self.score), &self.best_move).hash(state);
+    }
+}
+
+// rust exclusive methods
+impl TranspositionTable {
+    // the same board can end differently at another turn, so entries are only shared within a turn
+    pub fn key_of(state: &GameState) -> u64 {
+        state.zobrist() ^ zobrist::turn_key(state.turn)
+    }
+
+    fn slot(&self, key: u64) -> usize {
+        (key % self.entries.len() as u64) as usize
+    }
+
+    pub fn probe_key(&mut self, key: u64) -> Option<&TableEntry> {
+        let slot = self.slot(key);
+
+        match &self.entries[slot] {
+            Some(entry) if entry.key == key => {
+                self.hits += 1;
+            },
+            Some(_) => {
+                self.collisions += 1;
+                self.misses += 1;
+                return None;
+            },
+            None => {
+                self.misses += 1;
+                return None;
+            },
+        }
+
+        self.entries[slot].as_ref()
+    }
+
+    // replaces the old entry of the slot only if the new one was searched at least as deep
+    pub fn store_key(&mut self, entry: TableEntry) -> bool {
+        let slot = self.slot(entry.key);
+
+        let replace = match &self.entries[slot] {
+            Some(old) => old.key == entry.key || entry.depth >= old.depth,
+            None => true,
+        };
+
+        if replace {
+            self.entries[slot] = Some(entry);
+            self.stores += 1;
+        }
+
+        replace
+    }
+}
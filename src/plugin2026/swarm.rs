@@ -0,0 +1,77 @@
+use std::borrow::Cow;
+
+use pyo3::*;
+
+use crate::plugin2026::{
+    board::Board,
+    rules_engine::RulesEngine,
+    utils::{
+        binary,
+        coordinate::Coordinate,
+        hashing,
+        team::TeamEnum
+    }
+};
+
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Swarm {
+    #[pyo3(get)]
+    pub team: TeamEnum,
+    #[pyo3(get)]
+    pub members: Vec<Coordinate>,
+    #[pyo3(get)]
+    pub value: usize,
+}
+
+#[pymethods]
+impl Swarm {
+    #[new]
+    pub fn new(board: &Board, team: TeamEnum, members: Vec<Coordinate>) -> Self {
+        let value = RulesEngine::swarm_value(board, &members);
+        Self { team, members, value }
+    }
+
+    fn __str__(&self) -> String {self.to_string()}
+    fn __repr__(&self) -> String {format!("{:?}", self)}
+    fn __eq__(&self, other: &Swarm) -> bool {self == other}
+    fn __ne__(&self, other: &Swarm) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __copy__(&self) -> Swarm {self.clone()}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Swarm {self.clone()}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
+    fn __len__(&self) -> usize {self.members.len()}
+    fn deepcopy(&self) -> Swarm {self.clone()}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Swarm> {
+        binary::from_bytes(data)
+    }
+
+    pub fn size(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn contains(&self, position: &Coordinate) -> bool {
+        self.members.contains(position)
+    }
+
+    pub fn bounding_box(&self) -> Option<(Coordinate, Coordinate)> {
+        let min_x = self.members.iter().map(|c| c.x).min()?;
+        let min_y = self.members.iter().map(|c| c.y).min()?;
+        let max_x = self.members.iter().map(|c| c.x).max()?;
+        let max_y = self.members.iter().map(|c| c.y).max()?;
+
+        Some((Coordinate { x: min_x, y: min_y }, Coordinate { x: max_x, y: max_y }))
+    }
+}
+
+impl std::fmt::Display for Swarm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Schwarm von {} mit {} Fischen (Wert {})", self.team, self.members.len(), self.value)
+    }
+}
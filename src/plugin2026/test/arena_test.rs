@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod tests {
+    use crate::plugin2026::{
+        arena::{Arena, ArenaResult, EngineConfig, EngineKind, Sprt},
+        search::{evaluator::Evaluator, mcts::Playout, transposition_table::{BoundType, TableEntry}},
+        utils::hashing
+    };
+
+    fn result(wins: usize, draws: usize, losses: usize) -> ArenaResult {
+        ArenaResult { wins, draws, losses, ..Default::default() }
+    }
+
+    #[test]
+    pub fn elo_test() {
+        assert_eq!(result(5, 0, 5).elo(), 0.0);
+        assert!((result(3, 0, 1).elo() - 190.85).abs() < 0.01);
+        assert_eq!(result(1, 0, 0).elo(), f64::INFINITY);
+
+        // more games narrow the error bars
+        assert!(result(30, 40, 30).elo_error() < result(3, 4, 3).elo_error());
+        assert_eq!(result(4, 0, 0).elo_error(), f64::INFINITY);
+    }
+
+    #[test]
+    pub fn sprt_test() {
+        let sprt = Sprt::new(0.0, 10.0, 0.05, 0.05);
+        let (lower, upper) = sprt.bounds();
+        assert!((upper - 2.944).abs() < 0.001);
+        assert_eq!(lower, -upper);
+
+        assert_eq!(sprt.decide(10, 10, 10), None);
+        assert_eq!(sprt.decide(600, 100, 300), Some(true));
+        assert_eq!(sprt.decide(300, 100, 600), Some(false));
+    }
+
+    #[test]
+    pub fn hash_test() {
+        // values that compare equal hash equal, including both zeros
+        let evaluator = Evaluator::new(1.0, 0.0, -2.0, -1.0, 0.0, 0.5);
+        let negative_zero = Evaluator::new(1.0, -0.0, -2.0, -1.0, 0.0, 0.5);
+        assert_eq!(evaluator, negative_zero);
+        assert_eq!(hashing::value_hash(&evaluator), hashing::value_hash(&negative_zero));
+        assert_ne!(hashing::value_hash(&evaluator), hashing::value_hash(&Evaluator::default()));
+
+        let config = EngineConfig::new(EngineKind::AlphaBeta, 3, 1.0, Playout::Random, Some(evaluator));
+        assert_eq!(hashing::value_hash(&config), hashing::value_hash(&config.clone()));
+        assert_ne!(hashing::value_hash(&config), hashing::value_hash(&EngineConfig::new(EngineKind::AlphaBeta, 3, 1.5, Playout::Random, None)));
+
+        assert_eq!(hashing::value_hash(&Sprt::new(0.0, 5.0, 0.05, 0.05)), hashing::value_hash(&Sprt::new(-0.0, 5.0, 0.05, 0.05)));
+        assert_ne!(hashing::value_hash(&Sprt::new(0.0, 5.0, 0.05, 0.05)), hashing::value_hash(&Sprt::new(0.0, 10.0, 0.05, 0.05)));
+
+        let played = ArenaResult { llr: Some(0.5), ..result(3, 1, 2) };
+        assert_eq!(hashing::value_hash(&played), hashing::value_hash(&played.clone()));
+        assert_ne!(hashing::value_hash(&played), hashing::value_hash(&result(3, 1, 2)));
+
+        let entry = TableEntry { key: 7, bound: BoundType::Exact, depth: 2, score: 0.25, best_move: None };
+        assert_eq!(hashing::value_hash(&entry), hashing::value_hash(&entry.clone()));
+        assert_ne!(hashing::value_hash(&entry), hashing::value_hash(&TableEntry { score: 0.5, ..entry.clone() }));
+    }
+
+    #[test]
+    pub fn run_test() {
+        let first = EngineConfig::new(EngineKind::AlphaBeta, 2, 0.0, Playout::Random, None);
+        let second = EngineConfig::new(EngineKind::MonteCarlo, 0, std::f64::consts::SQRT_2, Playout::Random, None);
+        let arena = Arena::new(4, Some(2), 20, 7, None);
+
+        let played = arena.run(&first, &second);
+        assert_eq!(played.games(), 4);
+        assert_eq!(played.llr, None);
+
+        // an sprt stops as soon as it can decide
+        let test = Sprt::new(-1000.0, -999.0, 0.5, 0.5);
+        let sprt = Arena::new(100, Some(4), 50, 7, Some(test.clone()));
+        let stopped = sprt.run(&first, &first);
+        assert!(stopped.games() < 100);
+        assert!(stopped.sprt_accepted.is_some());
+
+        // games that finish after the decision are not counted
+        assert_eq!(test.decide(stopped.wins, stopped.draws, stopped.losses), stopped.sprt_accepted);
+        assert_eq!(Some(test.llr(stopped.wins, stopped.draws, stopped.losses)), stopped.llr);
+    }
+}
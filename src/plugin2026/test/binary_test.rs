@@ -0,0 +1,183 @@
+#[cfg(test)]
+mod tests {
+    use pyo3::{exceptions::PyTypeError, prelude::*, types::PyAnyMethods};
+
+    use crate::plugin2026::{
+        arena::{Arena, ArenaResult, EngineConfig, EngineKind, Sprt},
+        board::Board, field_type::FieldType, game_result::{GameResult, WinReason}, game_state::{GameState, UndoToken},
+        r#match::{Match, MatchResult}, r#move::Move, replay::{Replay, ReplayIterator}, rules_engine::RulesEngine,
+        search::{
+            evaluator::{EvaluationFunction, Evaluator}, mcts::{MonteCarloSearcher, Playout}, searcher::Searcher,
+            transposition_table::{BoundType, TableEntry, TranspositionTable}
+        },
+        swarm::Swarm, test::common::create_test_game_state,
+        utils::{constants::PluginConstants, coordinate::Coordinate, direction::Direction, random::Random, team::TeamEnum},
+        xml_codec::{ScoreEntry, ServerResult, XmlCodec}
+    };
+
+    #[test]
+    pub fn round_trip_test() {
+        pyo3::prepare_freethreaded_python();
+
+        let mut state = create_test_game_state();
+        let token = state.make_move(&Move::new(Coordinate {x: 0, y: 3}, Direction::Right)).unwrap();
+
+        assert_eq!(GameState::from_bytes(&state.to_bytes()).unwrap(), state);
+        assert_eq!(Board::from_bytes(&state.board.to_bytes()).unwrap(), state.board);
+        assert_eq!(UndoToken::from_bytes(&token.to_bytes()).unwrap(), token);
+
+        let coordinate = Coordinate {x: -1, y: 300};
+        assert_eq!(Coordinate::from_bytes(&coordinate.to_bytes()).unwrap(), coordinate);
+        assert_eq!(FieldType::from_bytes(&FieldType::Squid.to_bytes()).unwrap(), FieldType::Squid);
+
+        let result = GameResult::new(Some(TeamEnum::Two), WinReason::Timeout, 12, 0);
+        assert_eq!(GameResult::from_bytes(&result.to_bytes()).unwrap(), result);
+
+        let swarm = RulesEngine::swarm_values(&state.board, &TeamEnum::One).remove(0);
+        assert_eq!(Swarm::from_bytes(&swarm.to_bytes()).unwrap(), swarm);
+    }
+
+    #[test]
+    pub fn search_round_trip_test() {
+        pyo3::prepare_freethreaded_python();
+
+        let evaluator = Evaluator::new(0.5, -1.25, 3.0, 1e-9, 0.0, -7.0);
+        assert_eq!(Evaluator::from_bytes(&evaluator.to_bytes()).unwrap(), evaluator);
+
+        let entry = TableEntry {
+            key: u64::MAX,
+            bound: BoundType::Lower,
+            depth: 7,
+            score: -999_998.5,
+            best_move: Some(Move::new(Coordinate {x: 0, y: 3}, Direction::Right)),
+        };
+        assert_eq!(TableEntry::from_bytes(&entry.to_bytes()).unwrap(), entry);
+        assert_eq!(BoundType::from_bytes(&BoundType::Upper.to_bytes()).unwrap(), BoundType::Upper);
+        assert_eq!(Playout::from_bytes(&Playout::Heuristic.to_bytes()).unwrap(), Playout::Heuristic);
+
+        let state = create_test_game_state();
+        let mut table = TranspositionTable::new(64);
+        table.store(&state, 3, 1.5, BoundType::Exact, None);
+        table.probe(&state);
+
+        let read = TranspositionTable::from_bytes(&table.to_bytes()).unwrap();
+        assert_eq!(format!("{:?}", read), format!("{:?}", table));
+        assert_eq!(read.clone().probe(&state), table.clone().probe(&state));
+    }
+
+    #[test]
+    pub fn arena_round_trip_test() {
+        pyo3::prepare_freethreaded_python();
+
+        let config = EngineConfig::new(EngineKind::MonteCarlo, 3, 0.7, Playout::Heuristic, Some(Evaluator::new(2.0, 1.0, -1.0, 0.0, 0.5, 0.0)));
+        assert_eq!(EngineConfig::from_bytes(&config.to_bytes()).unwrap(), config);
+        assert_eq!(EngineKind::from_bytes(&EngineKind::AlphaBeta.to_bytes()).unwrap(), EngineKind::AlphaBeta);
+
+        let sprt = Sprt::new(-1.5, 4.0, 0.05, 0.1);
+        assert_eq!(Sprt::from_bytes(&sprt.to_bytes()).unwrap(), sprt);
+
+        let result = ArenaResult { wins: 3, draws: 1, losses: 0, llr: Some(1.25), sprt_accepted: Some(false) };
+        assert_eq!(ArenaResult::from_bytes(&result.to_bytes()).unwrap(), result);
+
+        // the number of threads is not stored, it is set to the default of the reading machine
+        let arena = Arena::new(12, Some(3), 250, 99, Some(sprt.clone()));
+        let default = Arena::new(12, None, 250, 99, Some(sprt));
+        assert_eq!(format!("{:?}", Arena::from_bytes(&arena.to_bytes()).unwrap()), format!("{:?}", default));
+    }
+
+    #[test]
+    pub fn match_round_trip_test() {
+        pyo3::prepare_freethreaded_python();
+
+        let state = create_test_game_state();
+        let move_ = Move::new(Coordinate {x: 0, y: 3}, Direction::Right);
+        let next = state.perform_move(&move_).unwrap();
+
+        let played = MatchResult {
+            result: GameResult::new(None, WinReason::RoundLimit, 9, 9),
+            moves: vec![Some(move_), None],
+            move_times_ms: vec![1500, 0],
+            final_state: next.clone(),
+        };
+        assert_eq!(MatchResult::from_bytes(&played.to_bytes()).unwrap(), played);
+
+        let game = Match::new(state.clone(), 500);
+        assert_eq!(format!("{:?}", Match::from_bytes(&game.to_bytes()).unwrap()), format!("{:?}", game));
+
+        // names and fragments are stored as utf-8
+        let score = ScoreEntry { name: "Spieler 1".to_string(), team: TeamEnum::One, parts: vec![2, -16] };
+        assert_eq!(ScoreEntry::from_bytes(&score.to_bytes()).unwrap(), score);
+
+        let result = ServerResult {
+            fragments: vec!["Siegpunkte".to_string(), "Schwarmgröße".to_string()],
+            scores: vec![score],
+            winner: Some(TeamEnum::One),
+            regular: true,
+            reason: "Spieler 1 hat alle Fische zu einem Schwarm verbunden.".to_string(),
+        };
+        assert_eq!(ServerResult::from_bytes(&result.to_bytes()).unwrap(), result);
+
+        let replay = Replay::new(vec![state, next.clone()], Some(result));
+        assert_eq!(Replay::from_bytes(&replay.to_bytes()).unwrap(), replay);
+
+        // an iterator continues where it was pickled
+        let mut states = ReplayIterator { states: replay.states.into_iter() };
+        states.states.next();
+        let mut read = ReplayIterator::from_bytes(&states.to_bytes()).unwrap();
+        assert_eq!(read.states.next(), Some(next));
+        assert_eq!(read.states.next(), None);
+    }
+
+    #[test]
+    pub fn enum_codes_test() {
+        // the codes are part of the format, changing them breaks existing pickles
+        assert_eq!(TeamEnum::Two.to_bytes().as_ref(), &[1]);
+        assert_eq!(FieldType::Squid.to_bytes().as_ref(), &[6]);
+        assert_eq!(FieldType::Empty.to_bytes().as_ref(), &[7]);
+        assert_eq!(Direction::UpLeft.to_bytes().as_ref(), &[7]);
+        assert_eq!(WinReason::IllegalMove.to_bytes().as_ref(), &[4]);
+        assert_eq!(BoundType::Upper.to_bytes().as_ref(), &[2]);
+        assert_eq!(EngineKind::MonteCarlo.to_bytes().as_ref(), &[1]);
+    }
+
+    #[test]
+    pub fn not_picklable_test() {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let pickle = py.import_bound("pickle").unwrap();
+
+            let searcher = Py::new(py, Searcher::new(2, EvaluationFunction::default())).unwrap();
+            let mcts = Py::new(py, MonteCarloSearcher::new(1.4, Playout::Random, Random::new(1), EvaluationFunction::default())).unwrap();
+            for engine in [searcher.into_bound(py).into_any(), mcts.into_bound(py).into_any()] {
+                let error = pickle.call_method1("dumps", (engine,)).unwrap_err();
+                assert!(error.is_instance_of::<PyTypeError>(py));
+            }
+
+            // the classes with only static methods have no instances that could be pickled
+            assert!(py.get_type_bound::<XmlCodec>().call0().is_err());
+            assert!(py.get_type_bound::<RulesEngine>().call0().is_err());
+            assert!(py.get_type_bound::<PluginConstants>().call0().is_err());
+        });
+    }
+
+    #[test]
+    pub fn compact_test() {
+        // half a byte per field, then the turn and a flag for the missing last move
+        assert_eq!(create_test_game_state().board.to_bytes().len(), 50);
+        assert_eq!(create_test_game_state().to_bytes().len(), 52);
+    }
+
+    #[test]
+    pub fn invalid_data_test() {
+        pyo3::prepare_freethreaded_python();
+
+        let bytes = create_test_game_state().to_bytes();
+        assert!(GameState::from_bytes(&bytes[..40]).is_err());
+        assert!(GameState::from_bytes(&[bytes.as_ref(), &[0]].concat()).is_err());
+        assert!(Direction::from_bytes(&[8]).is_err());
+        assert!(Board::from_bytes(&[0xff; 50]).is_err());
+        assert!(ScoreEntry::from_bytes(&[1, 0xff, 0, 0]).is_err());
+        assert!(ArenaResult::from_bytes(&[0, 0, 0, 0, 2]).is_err());
+    }
+}
@@ -1,9 +1,14 @@
 #[cfg(test)]
 mod tests {
+    use pyo3::{prelude::*, types::PyAnyMethods};
+
     use crate::plugin2026::{
-        field_type::FieldType, test::common::*, utils::{
+        board::Board, field_type::FieldType, game_state::GameState, r#move::Move, test::common::*, utils::{
+            bitboard,
             coordinate::Coordinate,
-            direction::Direction
+            direction::Direction,
+            symmetry::Symmetry,
+            team::TeamEnum
         }
     };
 
@@ -23,8 +28,8 @@ mod tests {
         let mut b = create_test_board();
 
         // remove squids
-        b.map[2][6] = FieldType::Empty;
-        b.map[7][3] = FieldType::Empty;
+        b.set_field(&Coordinate {x: 6, y: 2}, FieldType::Empty);
+        b.set_field(&Coordinate {x: 3, y: 7}, FieldType::Empty);
 
         let one_s_positions = vec![
             Coordinate {x: 0, y: 2},
@@ -101,4 +106,178 @@ mod tests {
             FieldType::OneL, FieldType::OneS
         ]);
     }
+
+    #[test]
+    pub fn map_view_test() {
+        let b = create_test_board();
+        let map = b.map();
+
+        assert_eq!(map.len(), 10);
+        assert_eq!(map[0][7], FieldType::TwoL);
+        assert_eq!(map[2][6], FieldType::Squid);
+        assert_eq!(Board::new(map).unwrap(), b);
+
+        let mut empty = create_empty_board();
+        assert_eq!(empty.fish_mask(), 0);
+        empty.set_map(b.map()).unwrap();
+        assert_eq!(empty, b);
+
+        // other sizes are neither cut off nor filled up
+        assert!(Board::new(vec![vec![FieldType::Empty; 3]; 3]).is_err());
+        assert!(empty.set_map(vec![vec![FieldType::Empty; 11]; 10]).is_err());
+        assert_eq!(empty, b);
+    }
+
+    #[test]
+    pub fn masks_test() {
+        let b = create_test_board();
+
+        assert_eq!(b.team_mask(&TeamEnum::One).count_ones(), 16);
+        assert_eq!(b.team_mask(&TeamEnum::Two).count_ones(), 16);
+        assert_eq!(b.mask_of(FieldType::Squid), b.squid_mask());
+        assert_eq!(b.mask_of(FieldType::Empty).count_ones(), 66);
+        assert_eq!(b.occupied_mask() | b.mask_of(FieldType::Empty), bitboard::FULL);
+
+        assert_eq!(b.fish_count_on_line(&Coordinate {x: 2, y: 7}, &Direction::Right), 2);
+        assert_eq!(b.fish_count_on_line(&Coordinate {x: 2, y: 7}, &Direction::Up), 2);
+        assert_eq!(b.fish_count_on_line(&Coordinate {x: -1, y: 7}, &Direction::Up), 0);
+
+        // the left column of team one is one swarm, the corner does not connect to the right column
+        let left = bitboard::flood_fill(bitboard::bit_of(&Coordinate {x: 0, y: 1}), b.team_mask(&TeamEnum::One));
+        assert_eq!(left.count_ones(), 8);
+        assert_eq!(left & bitboard::RIGHT_COLUMN, 0);
+    }
+
+    #[test]
+    pub fn random_start_test() {
+        let board = Board::random_start(Some(42));
+
+        assert_eq!(board, Board::random_start(Some(42)));
+        assert_ne!(board, Board::random_start(Some(43)));
+
+        for team in [TeamEnum::One, TeamEnum::Two] {
+            let counts: Vec<usize> = team.get_fish_types().iter().map(|f| board.get_fields_by_type(*f).len()).collect();
+            assert_eq!(counts, vec![7, 5, 4]);
+        }
+
+        // team one on the columns, team two on the rows, corners stay empty
+        let corners = [(0, 0), (0, 9), (9, 0), (9, 9)].iter()
+            .fold(0, |mask, &(x, y)| mask | bitboard::bit_of(&Coordinate {x, y}));
+        assert_eq!(board.team_mask(&TeamEnum::One), (bitboard::LEFT_COLUMN | bitboard::RIGHT_COLUMN) & !corners);
+
+        for y in 0..10 {
+            for x in 0..10 {
+                let field = board.get_field(&Coordinate {x, y}).unwrap();
+                let mirrored = board.get_field(&Coordinate {x: y, y: x}).unwrap();
+
+                match field.get_team() {
+                    Some(_) => assert_eq!(field.get_value(), mirrored.get_value()),
+                    None if field == FieldType::Squid => {
+                        assert!((2..=7).contains(&x) && (2..=7).contains(&y));
+                        assert_eq!(board.get_field(&Coordinate {x: 9 - x, y: 9 - y}), Some(FieldType::Squid));
+                    },
+                    None => {},
+                }
+            }
+        }
+        assert_eq!(board.get_fields_by_type(FieldType::Squid).len(), 2);
+    }
+
+    #[test]
+    pub fn planes_test() {
+        let state = create_test_game_state();
+        let planes = state.board.planes();
+        assert_eq!(planes.len(), 800);
+
+        // every field is set in exactly one plane
+        for index in 0..100 {
+            assert_eq!((0..8).map(|plane| planes[plane * 100 + index]).sum::<f32>(), 1.0);
+        }
+        assert_eq!(planes[FieldType::Squid as usize * 100 + 2 * 10 + 6], 1.0);
+        assert_eq!(planes[FieldType::OneL as usize * 100 + 7 * 10], 1.0);
+
+        let mut later = state.clone();
+        later.turn = 15;
+        let planes = later.planes();
+        assert_eq!(planes.len(), 1000);
+        assert!(planes[800..900].iter().all(|&v| v == 0.0));
+        assert!(planes[900..].iter().all(|&v| v == 0.25));
+        assert!(state.planes()[800..900].iter().all(|&v| v == 1.0));
+    }
+
+    #[test]
+    pub fn to_numpy_test() {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            // numpy is optional, without it a memoryview with the same shape takes the place of the array
+            py.run_bound(r#"
+import math, sys, types
+
+try:
+    import numpy
+except ImportError:
+    numpy = types.ModuleType("numpy")
+    numpy.empty = lambda shape, dtype: memoryview(bytearray(4 * math.prod(shape))).cast("f", shape)
+    sys.modules["numpy"] = numpy
+"#, None, None).unwrap();
+
+            let state = create_test_game_state();
+            for (array, planes) in [(state.board.to_numpy(py), state.board.planes()), (state.to_planes(py), state.planes())] {
+                let array = array.unwrap().into_bound(py);
+                let shape: Vec<usize> = array.getattr("shape").unwrap().extract().unwrap();
+                assert_eq!(shape, vec![planes.len() / 100, 10, 10]);
+
+                let values: Vec<Vec<Vec<f32>>> = array.call_method0("tolist").unwrap().extract().unwrap();
+                assert_eq!(values.concat().concat(), planes);
+            }
+        });
+    }
+
+    #[test]
+    pub fn symmetry_test() {
+        let state = create_test_game_state();
+        let moved = state.perform_move(&Move::new(Coordinate {x: 0, y: 3}, Direction::Right)).unwrap();
+
+        assert_eq!(Direction::Up.transform(Symmetry::Rotate90), Direction::Left);
+        assert_eq!(Coordinate {x: 0, y: 3}.transform(Symmetry::Rotate90), Coordinate {x: 6, y: 0});
+        assert_eq!(Coordinate {x: 0, y: 3}.transform(Symmetry::AntiTranspose), Coordinate {x: 6, y: 9});
+
+        for symmetry in Symmetry::all_symmetries() {
+            let board = moved.board.transform(symmetry);
+            assert_eq!(board.transform(symmetry.inverse()), moved.board);
+
+            // moves are transformed along, with swapped teams the other team has to be on turn
+            let mirrored = if symmetry.swaps_teams() {
+                assert!(moved.transform(symmetry).is_err());
+                GameState::new(board.clone(), moved.turn + 1, None)
+            } else {
+                let transformed = moved.transform(symmetry).unwrap();
+                assert_eq!(transformed.transform(symmetry.inverse()).unwrap(), moved);
+                transformed
+            };
+            let mut expected: Vec<Move> = moved.possible_moves().iter().map(|m| m.transform(symmetry)).collect();
+            let mut actual = mirrored.possible_moves();
+            expected.sort();
+            actual.sort();
+            assert_eq!(actual, expected, "{:?}", symmetry);
+
+            let team = if symmetry.swaps_teams() { TeamEnum::Two } else { TeamEnum::One };
+            assert_eq!(board.get_fields_by_type(FieldType::OneS).len(), moved.board.get_fields_by_type(team.get_fish_types()[0]).len());
+        }
+    }
+
+    #[test]
+    pub fn canonical_test() {
+        let state = create_test_game_state().perform_move(&Move::new(Coordinate {x: 0, y: 3}, Direction::Right)).unwrap();
+        let (canonical, symmetry) = state.canonical();
+
+        assert!(!symmetry.swaps_teams());
+        assert_eq!(canonical.transform(symmetry.inverse()).unwrap(), state);
+
+        // every equivalent state has the same representative
+        for other in [Symmetry::Rotate180, Symmetry::FlipHorizontal, Symmetry::FlipVertical] {
+            assert_eq!(state.transform(other).unwrap().canonical().0, canonical);
+        }
+    }
 }
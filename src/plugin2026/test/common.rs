@@ -34,7 +34,11 @@ pub fn create_test_board() -> Board {
         }
     }
 
-    Board {map: new_map}
+    Board::new(new_map).unwrap()
+}
+
+pub fn create_empty_board() -> Board {
+    Board::empty()
 }
 
 pub fn create_test_game_state() -> GameState {
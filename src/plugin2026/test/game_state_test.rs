@@ -1,7 +1,9 @@
 #[cfg(test)]
 mod tests {
     use crate::plugin2026::{
-        field_type::FieldType, r#move::Move, test::common::create_test_game_state, utils::{coordinate::Coordinate, direction::Direction}
+        board::Board, errors::MoveError, field_type::FieldType, game_result::WinReason, game_state::GameState, r#move::Move,
+        test::common::{create_empty_board, create_test_game_state},
+        utils::{coordinate::Coordinate, direction::Direction, hashing, team::TeamEnum}
     };
 
     #[test]
@@ -37,6 +39,60 @@ mod tests {
         assert_eq!(state.possible_moves().len(), 42); // team two
     }
 
+    #[test]
+    pub fn hash_and_order_test() {
+        let state = create_test_game_state();
+        let moved = state.perform_move(&Move::new(Coordinate {x: 0, y: 3}, Direction::Right)).unwrap();
+
+        // equal values hash equal, independent of how they were reached
+        let mut back = moved.clone();
+        back.board = state.board.clone();
+        back.turn = 0;
+        back.last_move = None;
+        assert_eq!(hashing::value_hash(&back), hashing::value_hash(&state));
+        assert_ne!(hashing::value_hash(&moved), hashing::value_hash(&state));
+
+        // moves sort by their start column, then row, then direction
+        let mut moves = state.possible_moves();
+        moves.sort();
+        assert_eq!(moves.first(), Some(&Move::new(Coordinate {x: 0, y: 1}, Direction::Up)));
+        assert!(moves.windows(2).all(|pair| (pair[0].start.x, pair[0].start.y) <= (pair[1].start.x, pair[1].start.y)));
+        assert!(Coordinate {x: 1, y: 0} > Coordinate {x: 0, y: 9});
+    }
+
+    #[test]
+    pub fn move_index_test() {
+        pyo3::prepare_freethreaded_python();
+
+        for index in 0..800 {
+            assert_eq!(Move::from_index(index).unwrap().to_index(), Ok(index));
+        }
+        assert!(Move::from_index(800).is_err());
+
+        let move_ = Move::new(Coordinate {x: 3, y: 2}, Direction::Left);
+        assert_eq!(move_.to_index(), Ok((2 * 10 + 3) * 8 + 6));
+        assert_eq!(Move::new(Coordinate {x: 10, y: 0}, Direction::Up).to_index(), Err(MoveError::StartOutOfBounds {start: Coordinate {x: 10, y: 0}}));
+    }
+
+    #[test]
+    pub fn perft_test() {
+        let state = create_test_game_state();
+
+        assert_eq!(state.perft(0), 1);
+        assert_eq!(state.perft(1), 48);
+        assert_eq!(state.perft(2), 1984);
+        assert_eq!(state.perft(3), 95388);
+
+        // divide splits the same count by the first move
+        let divided = state.divide(3);
+        assert_eq!(divided.len(), 48);
+        assert_eq!(divided.iter().map(|(_, nodes)| nodes).sum::<u64>(), 95388);
+
+        for (move_, nodes) in state.divide(2) {
+            assert_eq!(nodes, state.perform_move(&move_).unwrap().possible_moves().len() as u64);
+        }
+    }
+
     #[test]
     pub fn perform_move_test() {
         pyo3::prepare_freethreaded_python();
@@ -77,11 +133,164 @@ mod tests {
         });
         assert!(result.is_err(), "Move TO squid field should fail, but succeeded");
 
-        state.board.map[3][2] = FieldType::TwoL;
+        state.board.set_field(&Coordinate {x: 2, y: 3}, FieldType::TwoL);
         let result = state.perform_move(&Move {
             start: Coordinate { x: 2, y: 0 },
             direction: Direction::Up
         });
         assert!(result.is_err(), "Move TO own-fish field should fail, but succeeded");
     }
+
+    #[test]
+    pub fn game_over_test() {
+        let mut state = create_test_game_state();
+        assert!(!state.is_over());
+        assert_eq!(state.game_result(), None);
+
+        // team one is connected, team two is split up
+        let mut board = create_empty_board();
+        board.set_field(&Coordinate {x: 0, y: 0}, FieldType::OneL);
+        board.set_field(&Coordinate {x: 1, y: 1}, FieldType::OneS);
+        board.set_field(&Coordinate {x: 5, y: 5}, FieldType::TwoL);
+        board.set_field(&Coordinate {x: 9, y: 9}, FieldType::TwoL);
+        state = GameState::new(board, 4, None);
+
+        let result = state.game_result().unwrap();
+        assert_eq!(result.winner, Some(TeamEnum::One));
+        assert_eq!(result.reason, WinReason::SwarmConnected);
+        assert_eq!((result.team_one_score, result.team_two_score), (4, 3));
+
+        // swarms only count at the end of a round
+        state.turn = 5;
+        assert!(!state.is_over());
+
+        // both connected in the same round, larger swarm wins
+        state.turn = 6;
+        state.board.set_field(&Coordinate {x: 9, y: 9}, FieldType::Empty);
+        state.board.set_field(&Coordinate {x: 6, y: 5}, FieldType::TwoL);
+        let result = state.game_result().unwrap();
+        assert_eq!(result.winner, Some(TeamEnum::Two));
+        assert_eq!(result.reason, WinReason::BothConnected);
+
+        // equal swarm values are a draw
+        state.board.set_field(&Coordinate {x: 6, y: 5}, FieldType::TwoS);
+        let result = state.game_result().unwrap();
+        assert_eq!(result.winner, None);
+        assert!(result.is_draw());
+    }
+
+    #[test]
+    pub fn round_limit_test() {
+        let mut state = create_test_game_state();
+
+        state.turn = 59;
+        assert!(!state.is_over());
+
+        state.turn = 60;
+        let result = state.game_result().unwrap();
+        assert_eq!(result.reason, WinReason::RoundLimit);
+        assert_eq!((result.team_one_score, result.team_two_score), (15, 15));
+        assert_eq!(state.winner(), None);
+
+        // team two loses its most valuable fish
+        state.board.set_field(&Coordinate {x: 3, y: 9}, FieldType::Empty);
+        assert_eq!(state.winner(), Some(TeamEnum::One));
+    }
+
+    #[test]
+    pub fn zobrist_test() {
+        let state = create_test_game_state();
+
+        let a = Move {start: Coordinate {x: 0, y: 3}, direction: Direction::Right};
+        let b = Move {start: Coordinate {x: 4, y: 9}, direction: Direction::Down};
+        let c = Move {start: Coordinate {x: 9, y: 6}, direction: Direction::Left};
+
+        let first = state.perform_move(&a).unwrap().perform_move(&b).unwrap().perform_move(&c).unwrap();
+        let second = state.perform_move(&c).unwrap().perform_move(&b).unwrap().perform_move(&a).unwrap();
+
+        // the incrementally updated key equals the key of a freshly built board
+        assert_eq!(first.board.zobrist(), Board::new(first.board.map()).unwrap().zobrist());
+        assert_ne!(first.zobrist(), state.zobrist());
+
+        // transpositions share a key, even with a different last move
+        assert_eq!(first.board, second.board);
+        assert_eq!(first.zobrist(), second.zobrist());
+
+        // the team on turn is part of the key
+        let mut other_team = first.clone();
+        other_team.turn += 1;
+        assert_ne!(first.zobrist(), other_team.zobrist());
+        assert_eq!(first.board.zobrist(), other_team.board.zobrist());
+    }
+
+    #[test]
+    pub fn make_unmake_move_test() {
+        pyo3::prepare_freethreaded_python();
+
+        let state = create_test_game_state();
+        let mut current = state.clone();
+
+        let a = Move {start: Coordinate {x: 0, y: 3}, direction: Direction::Right};
+        let b = Move {start: Coordinate {x: 4, y: 9}, direction: Direction::Down};
+
+        let token_a = current.make_move(&a).unwrap();
+        let token_b = current.make_move(&b).unwrap();
+        assert_eq!(current, state.perform_move(&a).unwrap().perform_move(&b).unwrap());
+        assert_eq!((token_b.turn, token_b.last_move.clone()), (1, Some(a)));
+
+        // tokens only fit the last move
+        assert_eq!(current.unmake_move(&token_a), Err(MoveError::StaleUndoToken { start: token_a.move_.start }));
+
+        current.unmake_move(&token_b).unwrap();
+        current.unmake_move(&token_a).unwrap();
+        assert_eq!(current, state);
+        assert_eq!(current.zobrist(), state.zobrist());
+
+        // a captured fish comes back
+        let mut board = create_empty_board();
+        board.set_field(&Coordinate {x: 0, y: 0}, FieldType::OneS);
+        board.set_field(&Coordinate {x: 0, y: 2}, FieldType::TwoM);
+        // more than one swarm per team, so the game is not over yet
+        board.set_field(&Coordinate {x: 5, y: 5}, FieldType::OneS);
+        board.set_field(&Coordinate {x: 9, y: 9}, FieldType::TwoS);
+        let capture_state = GameState::new(board, 0, None);
+        let mut current = capture_state.clone();
+
+        let token = current.make_move(&Move {start: Coordinate {x: 0, y: 0}, direction: Direction::Up}).unwrap();
+        assert_eq!((token.target, token.captured), (Coordinate {x: 0, y: 2}, FieldType::TwoM));
+        assert_eq!(current.board.get_fields_by_type(FieldType::TwoM), vec![]);
+
+        current.unmake_move(&token).unwrap();
+        assert_eq!(current, capture_state);
+    }
+
+    #[test]
+    pub fn validate_move_test() {
+        let mut state = create_test_game_state();
+
+        let one = Move {start: Coordinate {x: 0, y: 3}, direction: Direction::Right};
+        let two = Move {start: Coordinate {x: 4, y: 9}, direction: Direction::Down};
+
+        assert!(state.is_legal(&one));
+        assert_eq!(state.validate_move(&one, None), Ok(()));
+        let before_opening = MoveError::StaleLastMove {start: one.start, expected: two.clone(), actual: None};
+        assert_eq!(state.validate_move(&one, Some(two.clone())), Err(before_opening));
+        assert!(!state.is_legal(&two));
+        assert_eq!(state.validate_move(&two, None), Err(MoveError::WrongTeam {start: two.start}));
+        assert_eq!(state.perform_move(&two), Err(MoveError::WrongTeam {start: two.start}));
+
+        state.perform_move_mut(&one).unwrap();
+        assert!(state.is_legal(&two));
+        assert_eq!(state.validate_move(&two, Some(one.clone())), Ok(()));
+
+        // the move was chosen before the last move was made
+        let other = Move {start: Coordinate {x: 0, y: 4}, direction: Direction::Right};
+        let stale = MoveError::StaleLastMove {start: two.start, expected: other.clone(), actual: Some(one.clone())};
+        assert_eq!(state.validate_move(&two, Some(other.clone())), Err(stale));
+
+        state.turn = 60;
+        assert_eq!(state.validate_move(&two, None), Err(MoveError::GameOver {start: two.start}));
+        assert_eq!(state.perform_move(&two), Err(MoveError::GameOver {start: two.start}));
+        assert_eq!(state.make_move(&two), Err(MoveError::GameOver {start: two.start}));
+    }
 }
@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod tests {
+    use crate::plugin2026::{
+        board::Board, field_type::FieldType, game_state::GameState, r#move::Move, test::common::create_test_game_state,
+        utils::{coordinate::Coordinate, direction::Direction}
+    };
+
+    #[test]
+    pub fn round_trip_test() {
+        pyo3::prepare_freethreaded_python();
+
+        let mut state = create_test_game_state();
+        state.perform_move_mut(&Move::new(Coordinate {x: 0, y: 3}, Direction::Right)).unwrap();
+
+        let restored = GameState::from_json(&state.to_json()).unwrap();
+        assert_eq!(restored, state);
+        // the zobrist key is not part of the json, so it has to be rebuilt
+        assert_eq!(restored.zobrist(), state.zobrist());
+    }
+
+    #[test]
+    pub fn format_test() {
+        let json = serde_json::to_value(create_test_game_state()).unwrap();
+
+        assert_eq!(json["turn"], 0);
+        assert!(json["last_move"].is_null());
+        assert_eq!(json["board"].as_array().unwrap().len(), 10);
+        assert_eq!(json["board"][0][0], "Empty");
+        assert_eq!(json["board"][1][0], "OneM");
+
+        let move_ = Move::new(Coordinate {x: 0, y: 3}, Direction::UpRight);
+        assert_eq!(serde_json::to_string(&move_).unwrap(), r#"{"start":{"x":0,"y":3},"direction":"UpRight"}"#);
+    }
+
+    #[test]
+    pub fn invalid_json_test() {
+        pyo3::prepare_freethreaded_python();
+
+        assert!(GameState::from_json("{}").is_err());
+        assert!(GameState::from_json(r#"{"board": [], "turn": 0, "last_move": null}"#).is_err());
+        assert!(serde_json::from_str::<Board>(&serde_json::to_string(&vec![vec![FieldType::Empty; 10]; 9]).unwrap()).is_err());
+        assert!(serde_json::from_str::<FieldType>(r#""Shark""#).is_err());
+    }
+}
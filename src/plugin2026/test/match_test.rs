@@ -0,0 +1,149 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use pyo3::{prelude::{PyAnyMethods, PyDictMethods}, types::PyDict, PyResult, Python};
+
+    use crate::plugin2026::{
+        field_type::FieldType, game_result::WinReason, game_state::GameState, r#match::{Match, Player, PyPlayer}, r#move::Move,
+        search::{evaluator::EvaluationFunction, searcher::Searcher},
+        test::common::{create_empty_board, create_test_game_state},
+        utils::{coordinate::Coordinate, direction::Direction, team::TeamEnum}
+    };
+
+    struct FirstMovePlayer;
+
+    impl Player for FirstMovePlayer {
+        fn calculate_move(&mut self, state: &GameState, _: Duration) -> PyResult<Option<Move>> {
+            Ok(state.possible_moves().first().cloned())
+        }
+    }
+
+    struct SlowPlayer;
+
+    impl Player for SlowPlayer {
+        fn calculate_move(&mut self, state: &GameState, time_limit: Duration) -> PyResult<Option<Move>> {
+            std::thread::sleep(time_limit * 2);
+            Ok(state.possible_moves().first().cloned())
+        }
+    }
+
+    #[test]
+    pub fn full_match_test() {
+        let game = Match::new(create_test_game_state(), 1000);
+        let mut searcher = Searcher::new(1, EvaluationFunction::default());
+
+        let played = game.play(&mut searcher, &mut FirstMovePlayer).unwrap();
+        assert!(!matches!(played.result.reason, WinReason::Timeout | WinReason::IllegalMove));
+        assert_eq!(played.final_state.game_result(), Some(played.result.clone()));
+        assert_eq!(played.moves.len(), played.move_times_ms.len());
+
+        assert_eq!(replay(create_test_game_state(), &played.moves), played.final_state);
+    }
+
+    // the move list replays the game, skipped turns only count up the turn
+    fn replay(mut state: GameState, moves: &[Option<Move>]) -> GameState {
+        for move_ in moves {
+            match move_ {
+                Some(move_) => state.perform_move_mut(move_).unwrap(),
+                None => state.turn += 1,
+            }
+        }
+        state
+    }
+
+    #[test]
+    pub fn skipped_turn_test() {
+        // both fish of team two are boxed in by squids
+        let mut board = create_empty_board();
+        board.set_field(&Coordinate {x: 0, y: 0}, FieldType::TwoS);
+        board.set_field(&Coordinate {x: 9, y: 9}, FieldType::TwoS);
+        for (x, y) in [(1, 0), (0, 1), (1, 1), (2, 2), (8, 9), (9, 8), (8, 8), (7, 7)] {
+            board.set_field(&Coordinate {x, y}, FieldType::Squid);
+        }
+        board.set_field(&Coordinate {x: 4, y: 5}, FieldType::OneM);
+        board.set_field(&Coordinate {x: 5, y: 3}, FieldType::OneS);
+        let start = GameState::new(board, 0, None);
+
+        let played = Match::new(start.clone(), 1000).play(&mut FirstMovePlayer, &mut FirstMovePlayer).unwrap();
+        assert!(played.moves[0].is_some());
+        assert_eq!(played.moves[1], None);
+        assert_eq!(played.moves.len(), played.move_times_ms.len());
+        assert_eq!(replay(start, &played.moves), played.final_state);
+    }
+
+    #[test]
+    pub fn forfeit_test() {
+        let game = Match::new(create_test_game_state(), 10);
+
+        let played = game.play(&mut FirstMovePlayer, &mut SlowPlayer).unwrap();
+        assert_eq!((played.result.winner, played.result.reason), (Some(TeamEnum::One), WinReason::Timeout));
+        assert_eq!(played.moves.len(), 1);
+
+        // team one plays a fish of team two
+        struct WrongTeamPlayer;
+        impl Player for WrongTeamPlayer {
+            fn calculate_move(&mut self, _: &GameState, _: Duration) -> PyResult<Option<Move>> {
+                Ok(Some(Move {start: Coordinate {x: 4, y: 9}, direction: Direction::Down}))
+            }
+        }
+
+        let played = game.play(&mut WrongTeamPlayer, &mut FirstMovePlayer).unwrap();
+        assert_eq!((played.result.winner, played.result.reason), (Some(TeamEnum::Two), WinReason::IllegalMove));
+        assert!(played.moves.is_empty());
+    }
+
+    #[test]
+    pub fn slow_evaluator_test() {
+        pyo3::prepare_freethreaded_python();
+
+        // the first iteration alone would take about a second, the searcher stops before the limit anyway
+        let slow = Python::with_gil(|py| {
+            let evaluation = py.eval_bound("lambda state: __import__('time').sleep(0.02) or 0.0", None, None).unwrap();
+            EvaluationFunction::from_py(Some(&evaluation)).unwrap()
+        });
+        let mut searcher = Searcher::new(4, slow);
+
+        let game = Match::new(create_test_game_state(), 100);
+        let mut two = SlowPlayer;
+        let played = game.play(&mut searcher, &mut two).unwrap();
+        assert_eq!((played.result.winner, played.result.reason), (Some(TeamEnum::One), WinReason::Timeout));
+        assert!(played.moves[0].is_some());
+        assert!(played.move_times_ms[0] <= 100);
+    }
+
+    #[test]
+    pub fn python_player_test() {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let globals = PyDict::new_bound(py);
+            py.run_bound(r#"
+class Handler:
+    def __init__(self):
+        self.state = None
+        self.updates = 0
+
+    def on_update(self, state):
+        self.state = state
+        self.updates += 1
+
+    def calculate_move(self):
+        return self.state.possible_moves()[-1]
+
+handler = Handler()
+"#, Some(&globals), None).unwrap();
+
+            let handler = globals.get_item("handler").unwrap().unwrap();
+            let mut one = PyPlayer::from_py(&handler).unwrap();
+            let mut two = FirstMovePlayer;
+
+            let played = Match::new(create_test_game_state(), 1000).play(&mut one, &mut two).unwrap();
+            let updates: usize = handler.getattr("updates").unwrap().extract().unwrap();
+            assert_eq!(updates, played.moves.len().div_ceil(2));
+
+            let not_a_player = py.eval_bound("42", None, None).unwrap();
+            assert!(PyPlayer::from_py(&not_a_player).is_err());
+        });
+    }
+}
@@ -3,4 +3,24 @@ mod common;
 #[cfg(test)]
 mod board_test;
 #[cfg(test)]
-mod game_state_test;
\ No newline at end of file
+mod game_state_test;
+#[cfg(test)]
+mod rules_engine_test;
+#[cfg(test)]
+mod search_test;
+#[cfg(test)]
+mod xml_codec_test;
+#[cfg(test)]
+mod replay_test;
+#[cfg(test)]
+mod match_test;
+#[cfg(test)]
+mod arena_test;
+#[cfg(test)]
+mod properties_test;
+#[cfg(test)]
+mod binary_test;
+#[cfg(test)]
+mod notation_test;
+#[cfg(all(test, feature = "serde"))]
+mod json_test;
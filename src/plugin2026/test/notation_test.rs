@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod tests {
+    use crate::plugin2026::{
+        board::Board, errors::{MoveError, NotationError}, game_state::GameState, r#move::Move, test::common::create_test_game_state,
+        utils::{coordinate::Coordinate, direction::Direction}
+    };
+
+    const TEST_BOARD: &str = "1msllmmss1/S8S/L2*5S/S8M/M8M/S8L/L8L/S5*2S/M8M/1mslsmsls1";
+
+    #[test]
+    pub fn to_notation_test() {
+        let mut state = create_test_game_state();
+        assert_eq!(state.to_notation(), Ok(format!("{} 0 -", TEST_BOARD)));
+
+        state.perform_move_mut(&Move::new(Coordinate {x: 0, y: 3}, Direction::Right)).unwrap();
+        assert!(state.to_notation().unwrap().ends_with(" 1 a4>R"));
+
+        // fields outside of the board have no name, so the notation could not be read back
+        let start = Coordinate {x: -1, y: 3};
+        state.last_move = Some(Move::new(start, Direction::Right));
+        assert_eq!(state.to_notation(), Err(MoveError::StartOutOfBounds { start }));
+    }
+
+    #[test]
+    pub fn round_trip_test() {
+        let mut state = create_test_game_state();
+        assert_eq!(GameState::from_notation(TEST_BOARD), Ok(state.clone()));
+
+        for _ in 0..6 {
+            let move_ = state.possible_moves()[3].clone();
+            state.perform_move_mut(&move_).unwrap();
+            assert_eq!(GameState::from_notation(&state.to_notation().unwrap()), Ok(state.clone()));
+        }
+    }
+
+    #[test]
+    pub fn parse_error_test() {
+        fn error(notation: &str) -> NotationError {
+            GameState::from_notation(notation).unwrap_err()
+        }
+
+        assert_eq!(error("").column, 0);
+        assert_eq!(error("1msllmmss1/S8S").message, "Expected 10 rows separated by '/', found 2");
+
+        let unknown = error(&TEST_BOARD.replace("L2*5S", "L2x5S"));
+        assert_eq!(unknown.column, 17);
+        assert!(unknown.message.starts_with("'x' is neither"));
+
+        assert_eq!(error(&TEST_BOARD.replace("L2*5S", "L2*6S")).message, "Row 8 has more than 10 fields");
+        assert_eq!(error(&TEST_BOARD.replace("S8S/L2", "S7S/L2")).message, "Row 9 has 9 fields instead of 10");
+        assert_eq!(error(&format!("{} x", TEST_BOARD)).message, "'x' is not a turn");
+        assert_eq!(error(&format!("{} 1 k4>R", TEST_BOARD)).column, TEST_BOARD.len() + 3);
+        assert_eq!(error(&format!("{} 1 a4>X", TEST_BOARD)).column, TEST_BOARD.len() + 6);
+    }
+
+    #[test]
+    pub fn move_notation_test() {
+        let board = create_test_game_state().board;
+        let move_ = Move::new(Coordinate {x: 0, y: 3}, Direction::Right);
+
+        assert_eq!(move_.to_notation(None), Ok("a4>R".to_string()));
+        assert_eq!(move_.to_notation(Some(&board)), Ok("a4>R=c4".to_string()));
+        assert_eq!(Move::new(Coordinate {x: 9, y: 9}, Direction::DownLeft).to_notation(None), Ok("j10>DL".to_string()));
+
+        let start = Coordinate {x: 0, y: 3};
+        let target = Coordinate {x: -2, y: 3};
+        assert_eq!(Move::new(start, Direction::Left).to_notation(Some(&board)), Err(MoveError::TargetOutOfBounds { start, target }));
+
+        for text in ["a4>R", "a4>r", "A4 RIGHT", "a4 Right", "a4>R=c4", "a4>R=j4"] {
+            assert_eq!(Move::parse(text, None), Ok(move_.clone()), "{}", text);
+        }
+        assert_eq!(Move::parse("b2 UP_RIGHT", None), Ok(Move::new(Coordinate {x: 1, y: 1}, Direction::UpRight)));
+        assert_eq!(Move::parse("a4>R=c4", Some(&board)), Ok(move_.clone()));
+    }
+
+    #[test]
+    pub fn move_parse_error_test() {
+        let board = create_test_game_state().board;
+        fn error(text: &str, board: Option<&Board>) -> NotationError {
+            Move::parse(text, board).unwrap_err()
+        }
+
+        assert_eq!(error("a4", None).message, "'a4' is not a move like a4>R, a4>R=c4 or A4 RIGHT");
+        assert_eq!(error("k4>R", None).column, 0);
+        assert_eq!(error("a+4>R", None).message, "'a+4' is not a field from a1 to j10");
+        assert_eq!(error("a4>R=c+4", None).column, 5);
+        assert_eq!(error("a4>RIGHTER", None).column, 3);
+        assert_eq!(error("  a4 X", None).column, 5);
+
+        // the target has to lie in the direction, and on a board it has to be the actual target
+        assert_eq!(error("a4>R=c5", None).column, 5);
+        assert_eq!(error("a4>R=d4", Some(&board)).message, "d4 is not the target of a4>R");
+    }
+}
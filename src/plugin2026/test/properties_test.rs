@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::plugin2026::{
+        board::Board, errors::MoveError, game_state::GameState, rules_engine::RulesEngine, test::common::create_test_game_state,
+        utils::{bitboard, constants::PluginConstants, team::TeamEnum}
+    };
+
+    // a start position followed by random legal moves, the choices pick a move by index
+    fn random_game(seed: Option<u64>, choices: &[usize]) -> Vec<GameState> {
+        let mut state = match seed {
+            Some(seed) => GameState::new(Board::random_start(Some(seed)), 0, None),
+            None => create_test_game_state(),
+        };
+        let mut states = vec![state.clone()];
+
+        for choice in choices {
+            if state.is_over() {
+                break;
+            }
+
+            let moves = state.possible_moves();
+            if moves.is_empty() {
+                state.turn += 1;
+            } else {
+                state.perform_move_mut(&moves[choice % moves.len()]).unwrap();
+            }
+            states.push(state.clone());
+        }
+
+        states
+    }
+
+    fn games() -> impl Strategy<Value = Vec<GameState>> {
+        (prop::option::weighted(0.9, any::<u64>()), prop::collection::vec(any::<usize>(), 0..80))
+            .prop_map(|(seed, choices)| random_game(seed, &choices))
+    }
+
+    fn fish_count(state: &GameState, team: &TeamEnum) -> u32 {
+        state.board.team_mask(team).count_ones()
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn fish_count_never_increases(states in games()) {
+            for pair in states.windows(2) {
+                for team in [TeamEnum::One, TeamEnum::Two] {
+                    prop_assert!(fish_count(&pair[1], &team) <= fish_count(&pair[0], &team));
+                }
+            }
+        }
+
+        #[test]
+        fn games_end_at_the_round_limit(states in games()) {
+            let (last, played) = states.split_last().unwrap();
+            for state in played {
+                prop_assert!(!state.is_over());
+            }
+            if last.turn >= PluginConstants::ROUND_LIMIT * 2 {
+                prop_assert!(last.is_over());
+            }
+        }
+
+        #[test]
+        fn undo_restores_state(states in games()) {
+            for state in &states {
+                let mut copy = state.clone();
+                for move_ in state.possible_moves() {
+                    if state.is_over() {
+                        prop_assert_eq!(copy.make_move(&move_).unwrap_err(), MoveError::GameOver { start: move_.start });
+                        prop_assert_eq!(&copy, state);
+                        continue;
+                    }
+
+                    // a move that ends the game has to end it the same way after an undo
+                    let token = copy.make_move(&move_).unwrap();
+                    let result = copy.game_result();
+                    copy.unmake_move(&token).unwrap();
+                    prop_assert_eq!(&copy, state);
+                    prop_assert_eq!(copy.game_result(), None);
+                    prop_assert_eq!(copy.perform_move(&move_).unwrap().game_result(), result);
+                }
+            }
+        }
+
+        #[test]
+        fn possible_moves_are_executable(states in games()) {
+            for state in &states {
+                for move_ in state.possible_moves() {
+                    prop_assert_eq!(RulesEngine::can_execute_move(&state.board, &move_), Ok(()));
+
+                    let target = RulesEngine::target_position(&state.board, &move_);
+                    prop_assert!(bitboard::index_of(&target).is_some(), "{:?} leads out of bounds to {}", move_, target);
+                }
+            }
+        }
+
+        #[test]
+        fn swarms_partition_fish(states in games()) {
+            for state in &states {
+                for team in [TeamEnum::One, TeamEnum::Two] {
+                    let mut covered: bitboard::Bitboard = 0;
+
+                    for swarm in RulesEngine::swarms_of_team(&state.board, &team) {
+                        prop_assert!(!swarm.is_empty());
+                        for position in swarm {
+                            let bit = bitboard::bit_of(&position);
+                            prop_assert_eq!(covered & bit, 0, "{} is in two swarms", position);
+                            covered |= bit;
+                        }
+                    }
+
+                    prop_assert_eq!(covered, state.board.team_mask(&team));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod tests {
+    use crate::plugin2026::{
+        game_result::WinReason, game_state::GameState, r#move::Move, replay::Replay,
+        test::common::create_test_game_state,
+        utils::team::TeamEnum,
+        xml_codec::XmlCodec
+    };
+
+    fn create_test_log() -> (Vec<GameState>, String) {
+        let mut states = vec![create_test_game_state()];
+        for _ in 0..4 {
+            let last = states.last().unwrap();
+            let move_ = last.possible_moves()[0].clone();
+            states.push(last.perform_move(&move_).unwrap());
+        }
+
+        // every state is sent to both players, moves and other messages are in between
+        let mut log = String::from("<protocol>\n<room roomId=\"r\"><data class=\"welcomeMessage\" color=\"ONE\"/></room>\n");
+        for state in &states {
+            let memento = format!("<room roomId=\"r\"><data class=\"memento\">{}</data></room>\n", XmlCodec::state_to_xml(state));
+            log.push_str(&memento);
+            log.push_str(&memento);
+            if let Some(move_) = &state.last_move {
+                log.push_str(&XmlCodec::move_to_xml(move_, Some("r")));
+            }
+        }
+        log.push_str("<room roomId=\"r\"><data class=\"result\"><winner team=\"TWO\" regular=\"true\" reason=\"\"/></data></room>\n</protocol>");
+
+        (states, log)
+    }
+
+    #[test]
+    pub fn replay_from_log_test() {
+        let (states, log) = create_test_log();
+        let replay = Replay::from_xml(&log).unwrap();
+
+        assert_eq!(replay.states, states);
+        let moves: Vec<Option<Move>> = states[1..].iter().map(|s| s.last_move.clone()).chain([None]).collect();
+        assert_eq!(replay.moves(), states.iter().cloned().zip(moves).collect::<Vec<_>>());
+        assert_eq!(replay.result.as_ref().unwrap().winner, Some(TeamEnum::Two));
+
+        assert_eq!(replay.state_at(2), Some(states[2].clone()));
+        assert_eq!(replay.state_at(99), None);
+        assert_eq!(replay.final_state(), states.last().cloned());
+
+        // the moves lead from one state to the next
+        for (state, move_) in replay.moves().into_iter().filter_map(|(s, m)| Some((s, m?))) {
+            assert_eq!(state.perform_move(&move_).unwrap(), replay.states[state.turn + 1]);
+        }
+    }
+
+    #[test]
+    pub fn replay_with_gap_test() {
+        let (mut states, _) = create_test_log();
+        states.remove(2);
+
+        // no move leads from turn 1 to turn 3, the moves around the gap stay with their states
+        let replay = Replay::new(states.clone(), None);
+        let moves: Vec<(usize, Option<Move>)> = replay.moves().into_iter().map(|(s, m)| (s.turn, m)).collect();
+        assert_eq!(moves, vec![
+            (0, states[1].last_move.clone()),
+            (1, None),
+            (3, states[3].last_move.clone()),
+            (4, None),
+        ]);
+    }
+
+    #[test]
+    pub fn replay_from_server_replay_test() {
+        let (states, _) = create_test_log();
+
+        let xml = format!("<protocol>{}</protocol>", states.iter().map(XmlCodec::state_to_xml).collect::<String>());
+        let replay = Replay::from_xml(&xml).unwrap();
+        assert_eq!(replay.states, states);
+        assert_eq!(replay.result, None);
+    }
+
+    #[test]
+    pub fn replay_from_file_test() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/protocol-examples/piranhas-replay.xml");
+        let replay = Replay::from_file(path).unwrap();
+
+        assert_eq!(replay.states.len(), 53);
+        assert_eq!(replay.moves().iter().filter(|(_, m)| m.is_some()).count(), 52);
+
+        let result = replay.result.clone().unwrap();
+        assert_eq!(result.winner, Some(TeamEnum::Two));
+        assert!(result.regular);
+
+        // the server's result agrees with the one computed from the final state
+        let final_result = replay.final_state().unwrap().game_result().unwrap();
+        assert_eq!(final_result.winner, result.winner);
+        assert_eq!(final_result.reason, WinReason::SwarmConnected);
+        assert_eq!(result.score_of(&TeamEnum::Two).unwrap().parts, vec![2, final_result.team_two_score as i64]);
+    }
+
+    #[test]
+    pub fn replay_of_other_game_test() {
+        // the recorded examples are from the previous season's game, their boards cannot be read
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/protocol-examples/127.000.000.001.13050-127.000.000.001.43976.xml");
+        let xml = std::fs::read_to_string(path).unwrap();
+        assert!(Replay::from_xml(&xml).is_err());
+    }
+}
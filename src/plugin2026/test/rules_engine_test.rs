@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use pyo3::{prelude::PyAnyMethods, PyErr, Python};
+
+    use crate::plugin2026::{
+        board::Board, errors::{JumpsOverOpponentError, MoveError, PiranhasError}, field_type::FieldType, r#move::Move, rules_engine::RulesEngine, test::common::{create_empty_board, create_test_board}, utils::{
+            coordinate::Coordinate,
+            direction::Direction,
+            team::TeamEnum
+        }
+    };
+
+    #[test]
+    pub fn swarm_values_test() {
+        let b = create_test_board();
+
+        let swarms = RulesEngine::swarm_values(&b, &TeamEnum::One);
+        assert_eq!(swarms.len(), 2);
+        assert_eq!(swarms[0].value, 15);
+        assert_eq!(swarms[1].value, 14);
+        assert_eq!(swarms[0].size(), 8);
+        assert_eq!(swarms[0].bounding_box(), Some((Coordinate {x: 9, y: 1}, Coordinate {x: 9, y: 8})));
+        assert!(swarms[1].contains(&Coordinate {x: 0, y: 4}));
+
+        assert_eq!(RulesEngine::largest_swarm_value(&b, &TeamEnum::Two), 15);
+        assert_eq!(RulesEngine::largest_swarm_value(&create_empty_board(), &TeamEnum::Two), 0);
+    }
+
+    #[test]
+    pub fn compare_swarms_test() {
+        let mut b = create_test_board();
+        assert_eq!(RulesEngine::compare_swarms(&b), None);
+
+        // extend the left swarm of team one with a large fish
+        b.set_field(&Coordinate {x: 0, y: 0}, FieldType::OneL);
+        assert_eq!(RulesEngine::compare_swarms(&b), Some(TeamEnum::One));
+        assert_eq!(RulesEngine::largest_swarm_value(&b, &TeamEnum::One), 17);
+
+        b.set_field(&Coordinate {x: 0, y: 0}, FieldType::Empty);
+        b.set_field(&Coordinate {x: 9, y: 1}, FieldType::Empty);
+        assert_eq!(RulesEngine::compare_swarms(&b), Some(TeamEnum::Two));
+    }
+
+    #[test]
+    pub fn compare_tied_swarms_test() {
+        let mut b = create_empty_board();
+        b.set_field(&Coordinate {x: 0, y: 0}, FieldType::OneL);
+        b.set_field(&Coordinate {x: 5, y: 5}, FieldType::TwoM);
+        b.set_field(&Coordinate {x: 5, y: 6}, FieldType::TwoS);
+
+        // both largest swarms are worth 3, the one with more fish wins
+        assert_eq!(RulesEngine::largest_swarm_value(&b, &TeamEnum::One), RulesEngine::largest_swarm_value(&b, &TeamEnum::Two));
+        assert_eq!(RulesEngine::compare_swarms(&b), Some(TeamEnum::Two));
+
+        // with equal largest swarms the next swarm decides
+        b.set_field(&Coordinate {x: 5, y: 6}, FieldType::Empty);
+        b.set_field(&Coordinate {x: 5, y: 5}, FieldType::TwoL);
+        b.set_field(&Coordinate {x: 9, y: 9}, FieldType::OneS);
+        assert_eq!(RulesEngine::compare_swarms(&b), Some(TeamEnum::One));
+
+        b.set_field(&Coordinate {x: 2, y: 9}, FieldType::TwoS);
+        assert_eq!(RulesEngine::compare_swarms(&b), None);
+    }
+
+    #[test]
+    pub fn can_execute_move_test() {
+        let mut b = create_test_board();
+        fn check(b: &Board, x: isize, y: isize, direction: Direction) -> Result<(), MoveError> {
+            RulesEngine::can_execute_move(b, &Move {start: Coordinate {x, y}, direction})
+        }
+
+        assert_eq!(check(&b, 0, 3, Direction::Right), Ok(()));
+        assert_eq!(check(&b, -1, 0, Direction::Right), Err(MoveError::StartOutOfBounds {start: Coordinate {x: -1, y: 0}}));
+        assert_eq!(check(&b, 0, 3, Direction::Left), Err(MoveError::TargetOutOfBounds {start: Coordinate {x: 0, y: 3}, target: Coordinate {x: -2, y: 3}}));
+        assert_eq!(check(&b, 3, 3, Direction::Right), Err(MoveError::NotAFish {start: Coordinate {x: 3, y: 3}}));
+        assert_eq!(check(&b, 6, 0, Direction::Up), Err(MoveError::BlockedBySquid {start: Coordinate {x: 6, y: 0}, target: Coordinate {x: 6, y: 2}}));
+
+        b.set_field(&Coordinate {x: 2, y: 3}, FieldType::TwoL);
+        assert_eq!(check(&b, 2, 0, Direction::Up), Err(MoveError::OwnFishOnTarget {start: Coordinate {x: 2, y: 0}, target: Coordinate {x: 2, y: 3}}));
+
+        b.set_field(&Coordinate {x: 1, y: 1}, FieldType::TwoS);
+        let error = check(&b, 0, 1, Direction::Right).unwrap_err();
+        assert_eq!(error, MoveError::JumpsOverOpponent {start: Coordinate {x: 0, y: 1}, target: Coordinate {x: 3, y: 1}, over: Coordinate {x: 1, y: 1}});
+
+        // python sees a subclass of PiranhasError with the coordinates attached
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let err = PyErr::from(error);
+            assert!(err.is_instance_of::<JumpsOverOpponentError>(py));
+            assert!(err.is_instance_of::<PiranhasError>(py));
+
+            let over: Coordinate = err.value_bound(py).getattr("over").unwrap().extract().unwrap();
+            assert_eq!(over, Coordinate {x: 1, y: 1});
+        });
+    }
+}
@@ -0,0 +1,239 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use pyo3::Python;
+
+    use crate::plugin2026::{
+        field_type::FieldType, game_state::GameState, rules_engine::RulesEngine,
+        search::{evaluator::{EvaluationFunction, Evaluator}, mcts::{MonteCarloSearcher, Playout}, searcher::{Searcher, MAX_EVALUATION, WIN_SCORE}, transposition_table::{BoundType, TranspositionTable}},
+        test::common::{create_empty_board, create_test_game_state},
+        utils::{coordinate::Coordinate, random::Random, team::TeamEnum}
+    };
+
+    fn create_almost_connected_state() -> GameState {
+        // team one connects with a single move, team two cannot
+        let mut board = create_empty_board();
+        board.set_field(&Coordinate {x: 0, y: 0}, FieldType::OneM);
+        board.set_field(&Coordinate {x: 0, y: 3}, FieldType::OneL);
+        board.set_field(&Coordinate {x: 9, y: 9}, FieldType::TwoS);
+        board.set_field(&Coordinate {x: 9, y: 5}, FieldType::TwoS);
+
+        GameState::new(board, 0, None)
+    }
+
+    #[test]
+    pub fn search_finds_win_test() {
+        let state = create_almost_connected_state();
+        let mut searcher = Searcher::new(3, EvaluationFunction::default());
+
+        let (best, score, pv) = searcher.search(&state, Duration::from_secs(5)).unwrap();
+        assert!(score >= WIN_SCORE - 3.0);
+        assert_eq!(pv[0], best);
+        assert!(searcher.nodes > 0);
+
+        let next = state.perform_move(&best).unwrap();
+        assert!(RulesEngine::is_swarm_connected(&next.board, &TeamEnum::One));
+    }
+
+    #[test]
+    pub fn search_start_position_test() {
+        let state = create_test_game_state();
+        let mut searcher = Searcher::new(2, EvaluationFunction::default());
+
+        let (best, _, pv) = searcher.search(&state, Duration::from_secs(5)).unwrap();
+        assert!(state.possible_moves().contains(&best));
+        assert_eq!(searcher.depth, 2);
+        assert_eq!(pv.len(), 2);
+
+        // a finished game has no best move
+        let mut over = state.clone();
+        over.turn = 60;
+        assert_eq!(searcher.search(&over, Duration::from_secs(1)), None);
+    }
+
+    #[test]
+    pub fn search_without_time_test() {
+        let state = create_test_game_state();
+        let mut searcher = Searcher::new(4, EvaluationFunction::default());
+
+        let (best, score, pv) = searcher.search(&state, Duration::ZERO).unwrap();
+        assert!(searcher.depth >= 1);
+        assert_eq!(pv[0], best);
+        assert!(score.abs() < WIN_SCORE / 2.0);
+    }
+
+    #[test]
+    pub fn evaluator_test() {
+        let mut state = create_test_game_state();
+        let evaluator = Evaluator::default();
+
+        // the start position is symmetric for both teams
+        assert!(evaluator.evaluate(&state).abs() < 1e-9);
+
+        state.board.set_field(&Coordinate {x: 3, y: 9}, FieldType::Empty);
+        assert!(evaluator.evaluate(&state) > 0.0);
+
+        // the same position from the view of team two
+        state.turn = 1;
+        assert!(evaluator.evaluate(&state) < 0.0);
+
+        let mobility_only = Evaluator::new(0.0, 0.0, 0.0, 0.0, 1.0, 0.0);
+        assert_eq!(mobility_only.evaluate(&create_test_game_state()), 6.0);
+    }
+
+    #[test]
+    pub fn python_evaluator_test() {
+        pyo3::prepare_freethreaded_python();
+
+        let (evaluation, failing) = Python::with_gil(|py| {
+            let evaluation = py.eval_bound("lambda state: float(state.turn)", None, None).unwrap();
+            let failing = py.eval_bound("lambda state: 1 / 0", None, None).unwrap();
+
+            (
+                EvaluationFunction::from_py(Some(&evaluation)).unwrap(),
+                EvaluationFunction::from_py(Some(&failing)).unwrap()
+            )
+        });
+
+        let state = create_test_game_state();
+        assert_eq!(evaluation.evaluate(&state).unwrap(), 0.0);
+        assert!(failing.evaluate(&state).is_err());
+
+        let mut searcher = Searcher::new(1, evaluation);
+        assert!(searcher.search(&state, Duration::from_secs(5)).is_some());
+
+        Python::with_gil(|py| {
+            let not_callable = py.eval_bound("42", None, None).unwrap();
+            assert!(EvaluationFunction::from_py(Some(&not_callable)).is_err());
+        });
+    }
+
+    #[test]
+    pub fn python_evaluator_out_of_range_test() {
+        pyo3::prepare_freethreaded_python();
+
+        // scores beyond the range of the search, or no number at all, still give a move and a line
+        for (function, finite) in [("lambda state: 1e9", true), ("lambda state: -1e9", true), ("lambda state: float('nan')", false)] {
+            let evaluation = Python::with_gil(|py| {
+                EvaluationFunction::from_py(Some(&py.eval_bound(function, None, None).unwrap())).unwrap()
+            });
+
+            assert_eq!(evaluation.evaluate(&create_test_game_state()).is_ok(), finite, "{}", function);
+
+            let mut searcher = Searcher::new(2, evaluation);
+            let (best, score, pv) = searcher.search(&create_test_game_state(), Duration::from_secs(5)).unwrap();
+            assert_eq!(pv[0], best, "{}", function);
+
+            // clamped evaluations are no forced wins, so the search goes on to the full depth
+            if finite {
+                assert!(score.abs() <= MAX_EVALUATION, "{}", function);
+                assert_eq!(searcher.depth, 2, "{}", function);
+            }
+        }
+
+        let infinite = EvaluationFunction::Native(Evaluator::new(f64::INFINITY, 0.0, 0.0, 0.0, 0.0, 0.0));
+        assert!(infinite.evaluate(&create_test_game_state()).is_err());
+    }
+
+    #[test]
+    pub fn mcts_finds_win_test() {
+        let state = create_almost_connected_state();
+        let mut mcts = MonteCarloSearcher::new(1.4, Playout::Random, Random::new(7), EvaluationFunction::default());
+
+        let (best, win_rate, pv) = mcts.search(&state, None, Some(500)).unwrap();
+        assert_eq!(mcts.iterations, 500);
+        assert_eq!(pv[0], best);
+        assert!(win_rate > 0.9);
+
+        let next = state.perform_move(&best).unwrap();
+        assert!(RulesEngine::is_swarm_connected(&next.board, &TeamEnum::One));
+    }
+
+    #[test]
+    pub fn mcts_tree_reuse_test() {
+        let state = create_test_game_state();
+        let mut mcts = MonteCarloSearcher::new(1.4, Playout::Random, Random::new(3), EvaluationFunction::default());
+
+        let (best, _, _) = mcts.search(&state, None, Some(300)).unwrap();
+
+        // the most visited subtree is kept when searching the position after the best move
+        let next = state.perform_move(&best).unwrap();
+        mcts.search(&next, None, Some(1)).unwrap();
+        assert!(mcts.tree_size() > 2);
+
+        // an unrelated position starts a new tree
+        mcts.search(&create_almost_connected_state(), None, Some(1)).unwrap();
+        assert_eq!(mcts.tree_size(), 2);
+    }
+
+    #[test]
+    pub fn mcts_heuristic_playout_test() {
+        let state = create_test_game_state();
+        let mut mcts = MonteCarloSearcher::new(1.4, Playout::Heuristic, Random::new(11), EvaluationFunction::default());
+
+        let (best, win_rate, _) = mcts.search(&state, Some(Duration::from_secs(60)), Some(20)).unwrap();
+        assert!(state.possible_moves().contains(&best));
+        assert!((0.0..=1.0).contains(&win_rate));
+    }
+
+    #[test]
+    pub fn transposition_table_test() {
+        let state = create_test_game_state();
+        let other = create_almost_connected_state();
+        let best = state.possible_moves()[0].clone();
+
+        let mut table = TranspositionTable::new(1);
+        assert_eq!(table.probe(&state), None);
+
+        assert!(table.store(&state, 3, 1.5, BoundType::Exact, Some(best.clone())));
+        let entry = table.probe(&state).unwrap();
+        assert_eq!((entry.depth, entry.score, entry.bound, entry.best_move), (3, 1.5, BoundType::Exact, Some(best)));
+
+        // both positions share the only slot, a shallower entry does not replace a deeper one
+        assert!(!table.store(&other, 2, 0.0, BoundType::Lower, None));
+        assert_eq!(table.probe(&other), None);
+        assert!(table.store(&other, 3, 0.0, BoundType::Lower, None));
+        assert!(table.probe(&other).is_some());
+
+        assert_eq!((table.hits, table.misses, table.collisions, table.stores), (2, 2, 1, 2));
+        assert_eq!(table.filled(), 1);
+
+        table.clear();
+        assert_eq!((table.filled(), table.stores), (0, 0));
+    }
+
+    #[test]
+    pub fn searcher_table_test() {
+        let state = create_test_game_state();
+        let mut searcher = Searcher::new(3, EvaluationFunction::default());
+
+        let (best, _, _) = searcher.search(&state, Duration::from_secs(30)).unwrap();
+        assert!(searcher.table.stores > 0);
+        assert!(searcher.table.hits > 0);
+
+        // the root entry holds the best move of the last iteration
+        let entry = searcher.table.probe(&state).unwrap();
+        assert_eq!(entry.depth, 3);
+        assert_eq!(entry.best_move, Some(best));
+    }
+
+    #[test]
+    pub fn searcher_table_turn_test() {
+        // team one is ahead once the round limit is reached
+        let mut early = create_test_game_state();
+        early.board.set_field(&Coordinate {x: 0, y: 0}, FieldType::OneL);
+        early.turn = 2;
+        let mut late = early.clone();
+        late.turn = 58;
+
+        let mut searcher = Searcher::new(2, EvaluationFunction::default());
+        let (_, early_score, _) = searcher.search(&early, Duration::from_secs(30)).unwrap();
+        let (_, late_score, _) = searcher.search(&late, Duration::from_secs(30)).unwrap();
+
+        // entries of the early search must not be used this close to the round limit
+        let (_, fresh_score, _) = Searcher::new(2, EvaluationFunction::default()).search(&late, Duration::from_secs(30)).unwrap();
+        assert_eq!(late_score, fresh_score);
+        assert_ne!(late_score, early_score);
+    }
+}
@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod tests {
+    use crate::plugin2026::{
+        errors::XmlError, game_state::GameState, r#move::Move,
+        test::common::create_test_game_state,
+        utils::{coordinate::Coordinate, direction::Direction, team::TeamEnum},
+        xml_codec::{Element, RoomMessage, XmlCodec}
+    };
+
+    const MEMENTO: &str = r#"<room roomId="7ea01df9-23e1-4724-a909-bc9b324e8772">
+      <data class="memento">
+        <state class="state" startTeam="ONE" turn="1">
+          <board>
+              <row><field>EMPTY</field><field>TWO_M</field><field>TWO_S</field><field>TWO_L</field><field>TWO_S</field><field>TWO_M</field><field>TWO_S</field><field>TWO_L</field><field>TWO_S</field><field>EMPTY</field></row>
+              <row><field>ONE_M</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>ONE_M</field></row>
+              <row><field>ONE_S</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>SQUID</field><field>EMPTY</field><field>EMPTY</field><field>ONE_S</field></row>
+              <row><field>ONE_L</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>ONE_L</field></row>
+              <row><field>ONE_S</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>ONE_L</field></row>
+              <row><field>ONE_M</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>ONE_M</field></row>
+              <row><field>ONE_S</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>ONE_M</field></row>
+              <row><field>ONE_L</field><field>EMPTY</field><field>EMPTY</field><field>SQUID</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>ONE_S</field></row>
+              <row><field>ONE_S</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>EMPTY</field><field>ONE_S</field></row>
+              <row><field>EMPTY</field><field>TWO_M</field><field>TWO_S</field><field>TWO_L</field><field>TWO_L</field><field>TWO_M</field><field>TWO_M</field><field>TWO_S</field><field>TWO_S</field><field>EMPTY</field></row>
+          </board>
+          <lastMove>
+            <from x="0" y="4"/>
+            <direction>UP_RIGHT</direction>
+          </lastMove>
+        </state>
+      </data>
+    </room>"#;
+
+    const RESULT: &str = r#"<room roomId="7ea01df9-23e1-4724-a909-bc9b324e8772">
+      <data class="result">
+        <definition>
+          <fragment name="Siegpunkte">
+            <aggregation>SUM</aggregation>
+            <relevantForRanking>true</relevantForRanking>
+          </fragment>
+          <fragment name="Schwarmgröße">
+            <aggregation>AVERAGE</aggregation>
+            <relevantForRanking>true</relevantForRanking>
+          </fragment>
+        </definition>
+        <scores>
+          <entry>
+            <player name="Spieler 1" team="ONE" />
+            <score><part>2</part><part>16</part></score>
+          </entry>
+          <entry>
+            <player name="Spieler 2" team="TWO" />
+            <score><part>0</part><part>11</part></score>
+          </entry>
+        </scores>
+        <winner team="ONE" regular="true" reason="Spieler 1 hat alle Fische zu einem Schwarm verbunden." />
+      </data>
+    </room>"#;
+
+    #[test]
+    pub fn parse_state_test() {
+        let mut expected = create_test_game_state();
+        expected.turn = 1;
+        expected.last_move = Some(Move {start: Coordinate {x: 0, y: 4}, direction: Direction::UpRight});
+
+        let (room_id, message) = XmlCodec::parse_room(MEMENTO).unwrap();
+        assert_eq!(room_id, "7ea01df9-23e1-4724-a909-bc9b324e8772");
+        assert_eq!(message, RoomMessage::State(expected.clone()));
+        assert_eq!(XmlCodec::parse_state(MEMENTO).unwrap(), expected);
+
+        // state -> xml -> state keeps everything
+        let xml = XmlCodec::state_to_xml(&expected);
+        assert_eq!(XmlCodec::parse_state(&xml).unwrap(), expected);
+
+        let first = GameState::new(expected.board.clone(), 0, None);
+        assert_eq!(XmlCodec::parse_state(&XmlCodec::state_to_xml(&first)).unwrap(), first);
+    }
+
+    #[test]
+    pub fn move_round_trip_test() {
+        for direction in Direction::all_directions() {
+            let move_ = Move {start: Coordinate {x: 9, y: 2}, direction};
+
+            let data = XmlCodec::move_to_xml(&move_, None);
+            assert_eq!(XmlCodec::parse_move(&data).unwrap(), move_);
+
+            let room = XmlCodec::move_to_xml(&move_, Some("abc"));
+            assert_eq!(XmlCodec::parse_room(&room).unwrap(), ("abc".to_string(), RoomMessage::Move(move_)));
+        }
+
+        assert_eq!(
+            XmlCodec::move_to_xml(&Move {start: Coordinate {x: 0, y: 3}, direction: Direction::DownRight}, None),
+            "<data class=\"move\"><from x=\"0\" y=\"3\"/><direction>DOWN_RIGHT</direction></data>"
+        );
+    }
+
+    #[test]
+    pub fn parse_result_test() {
+        let result = XmlCodec::parse_result(RESULT).unwrap();
+
+        assert_eq!(result.fragments, vec!["Siegpunkte", "Schwarmgröße"]);
+        assert_eq!(result.winner, Some(TeamEnum::One));
+        assert!(result.regular);
+        assert_eq!(result.score_of(&TeamEnum::Two).unwrap().parts, vec![0, 11]);
+        assert_eq!(result.scores[0].name, "Spieler 1");
+
+        // a draw has no winning team
+        let draw = RESULT.replace("winner team=\"ONE\"", "winner");
+        assert_eq!(XmlCodec::parse_result(&draw).unwrap().winner, None);
+    }
+
+    #[test]
+    pub fn invalid_message_test() {
+        let other = r#"<room roomId="a"><data class="welcomeMessage" color="ONE"/></room>"#;
+        assert_eq!(XmlCodec::parse_room(other).unwrap().1, RoomMessage::Other("welcomeMessage".to_string()));
+        assert!(XmlCodec::parse_state(other).is_err());
+
+        assert_eq!(
+            XmlCodec::parse_state(&MEMENTO.replacen("SQUID", "OCTOPUS", 1)),
+            Err(XmlError::new("unknown field type OCTOPUS"))
+        );
+        // a truncated board is not filled up with empty fields
+        let short_row = MEMENTO.replacen("<field>EMPTY</field></row>", "</row>", 1);
+        assert_eq!(XmlCodec::parse_state(&short_row), Err(XmlError::new("board has to be 10x10 fields")));
+        let first_row = &MEMENTO[MEMENTO.find("<row>").unwrap()..MEMENTO.find("</row>").unwrap() + "</row>".len()];
+        assert!(XmlCodec::parse_state(&MEMENTO.replacen(first_row, "", 1)).is_err());
+
+        assert!(XmlCodec::parse_move("<data class=\"move\"><from x=\"a\" y=\"3\"/></data>").is_err());
+        assert!(XmlCodec::parse_room("<room roomId=\"a\"><data></room>").is_err());
+
+        // messages that were cut off are not valid
+        let cut = Element::parse("<protocol><room roomId=\"a\"><data class=\"memento\"/></room><room roomId=\"a\">");
+        assert_eq!(cut, Err(XmlError::new("<room> is not closed")));
+        assert!(XmlCodec::parse_state(&MEMENTO[..MEMENTO.len() - "</room>".len()]).is_err());
+
+        // the team on turn is derived from the turn, which only works if team one starts
+        let start_two = MEMENTO.replace("startTeam=\"ONE\"", "startTeam=\"TWO\"");
+        assert_eq!(XmlCodec::parse_state(&start_two), Err(XmlError::new("only games where team ONE starts are supported")));
+    }
+}
@@ -1,5 +1,14 @@
+pub mod bitboard;
 pub mod vector;
 pub mod direction;
 pub mod constants;
 pub mod coordinate;
-pub mod team;
\ No newline at end of file
+pub mod team;
+pub mod random;
+pub mod zobrist;
+pub mod binary;
+pub mod hashing;
+pub mod tensor;
+pub mod symmetry;
+#[cfg(feature = "serde")]
+pub mod json;
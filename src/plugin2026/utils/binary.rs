@@ -0,0 +1,342 @@
+use std::borrow::Cow;
+
+use pyo3::{exceptions::{PyTypeError, PyValueError}, prelude::PyAnyMethods, *};
+
+use crate::plugin2026::{
+    arena::{Arena, ArenaResult, EngineConfig, EngineKind, Sprt},
+    board::Board,
+    field_type::FieldType,
+    game_result::{GameResult, WinReason},
+    game_state::{GameState, UndoToken},
+    r#match::{Match, MatchResult},
+    r#move::Move,
+    replay::{Replay, ReplayIterator},
+    search::{
+        evaluator::Evaluator,
+        mcts::Playout,
+        transposition_table::{BoundType, TableEntry, TranspositionTable}
+    },
+    swarm::Swarm,
+    utils::{
+        bitboard,
+        constants::PluginConstants,
+        coordinate::Coordinate,
+        direction::Direction,
+        symmetry::Symmetry,
+        team::TeamEnum,
+        vector::Vector
+    },
+    xml_codec::{ScoreEntry, ServerResult}
+};
+
+// a compact encoding for pickle, numbers are varints and boards take half a byte per field
+pub trait Binary: Sized {
+    fn write(&self, out: &mut Vec<u8>);
+    fn read(input: &mut Reader<'_>) -> Option<Self>;
+}
+
+pub struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl Reader<'_> {
+    pub fn byte(&mut self) -> Option<u8> {
+        let (first, rest) = self.bytes.split_first()?;
+        self.bytes = rest;
+        Some(*first)
+    }
+
+    pub fn varint(&mut self) -> Option<u64> {
+        let mut value: u64 = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+        }
+        None
+    }
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+pub fn to_bytes<T: Binary>(value: &T) -> Cow<'static, [u8]> {
+    let mut out = Vec::new();
+    value.write(&mut out);
+    Cow::Owned(out)
+}
+
+pub fn from_bytes<T: Binary + PyTypeInfo>(bytes: &[u8]) -> PyResult<T> {
+    let mut reader = Reader { bytes };
+
+    match T::read(&mut reader) {
+        Some(value) if reader.bytes.is_empty() => Ok(value),
+        _ => Err(PyValueError::new_err(format!("Invalid binary data for {}", T::NAME))),
+    }
+}
+
+// searchers hold python callbacks and their search tree, they are created anew with the same settings instead
+pub fn not_picklable<T: PyTypeInfo>() -> PyErr {
+    PyTypeError::new_err(format!("{} can not be pickled, create a new one with the same settings", T::NAME))
+}
+
+// pickle rebuilds the object by calling from_bytes of its class
+pub fn reduce<T: Binary + PyTypeInfo>(py: Python<'_>, value: &T) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {
+    let from_bytes = py.get_type_bound::<T>().getattr("from_bytes")?;
+    Ok((from_bytes.unbind(), (to_bytes(value),)))
+}
+
+impl Binary for usize {
+    fn write(&self, out: &mut Vec<u8>) {
+        write_varint(out, *self as u64);
+    }
+
+    fn read(input: &mut Reader<'_>) -> Option<Self> {
+        usize::try_from(input.varint()?).ok()
+    }
+}
+
+// zigzag encoded, so small negative numbers stay small
+impl Binary for isize {
+    fn write(&self, out: &mut Vec<u8>) {
+        let value = *self as i64;
+        write_varint(out, ((value << 1) ^ (value >> 63)) as u64);
+    }
+
+    fn read(input: &mut Reader<'_>) -> Option<Self> {
+        let value = input.varint()?;
+        isize::try_from((value >> 1) as i64 ^ -((value & 1) as i64)).ok()
+    }
+}
+
+impl Binary for u64 {
+    fn write(&self, out: &mut Vec<u8>) {
+        write_varint(out, *self);
+    }
+
+    fn read(input: &mut Reader<'_>) -> Option<Self> {
+        input.varint()
+    }
+}
+
+impl Binary for i64 {
+    fn write(&self, out: &mut Vec<u8>) {
+        (*self as isize).write(out);
+    }
+
+    fn read(input: &mut Reader<'_>) -> Option<Self> {
+        isize::read(input).map(|value| value as i64)
+    }
+}
+
+// the exact bits, so weights and scores come back unchanged
+impl Binary for f64 {
+    fn write(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.to_le_bytes());
+    }
+
+    fn read(input: &mut Reader<'_>) -> Option<Self> {
+        let mut bytes = [0; 8];
+        for byte in bytes.iter_mut() {
+            *byte = input.byte()?;
+        }
+        Some(f64::from_le_bytes(bytes))
+    }
+}
+
+impl Binary for bool {
+    fn write(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+
+    fn read(input: &mut Reader<'_>) -> Option<Self> {
+        match input.byte()? {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+}
+
+impl Binary for String {
+    fn write(&self, out: &mut Vec<u8>) {
+        self.as_bytes().to_vec().write(out);
+    }
+
+    fn read(input: &mut Reader<'_>) -> Option<Self> {
+        String::from_utf8(Vec::read(input)?).ok()
+    }
+}
+
+impl Binary for u8 {
+    fn write(&self, out: &mut Vec<u8>) {
+        out.push(*self);
+    }
+
+    fn read(input: &mut Reader<'_>) -> Option<Self> {
+        input.byte()
+    }
+}
+
+impl<T: Binary> Binary for Option<T> {
+    fn write(&self, out: &mut Vec<u8>) {
+        match self {
+            Some(value) => {
+                out.push(1);
+                value.write(out);
+            },
+            None => out.push(0),
+        }
+    }
+
+    fn read(input: &mut Reader<'_>) -> Option<Self> {
+        match input.byte()? {
+            0 => Some(None),
+            1 => Some(Some(T::read(input)?)),
+            _ => None,
+        }
+    }
+}
+
+impl<T: Binary> Binary for Vec<T> {
+    fn write(&self, out: &mut Vec<u8>) {
+        self.len().write(out);
+        for value in self {
+            value.write(out);
+        }
+    }
+
+    fn read(input: &mut Reader<'_>) -> Option<Self> {
+        let len = usize::read(input)?;
+        // every value takes at least one byte, so broken lengths fail before allocating
+        if len > input.bytes.len() {
+            return None;
+        }
+        (0..len).map(|_| T::read(input)).collect()
+    }
+}
+
+// enums are stored by a fixed code per variant, so reordering the variants keeps old data readable
+macro_rules! binary_enum {
+    ($type:ident { $($variant:ident = $code:literal),* }) => {
+        impl Binary for $type {
+            fn write(&self, out: &mut Vec<u8>) {
+                out.push(match self { $($type::$variant => $code),* });
+            }
+
+            fn read(input: &mut Reader<'_>) -> Option<Self> {
+                match input.byte()? {
+                    $($code => Some($type::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+// structs are stored field by field in the given order
+macro_rules! binary_struct {
+    ($type:ident { $($field:ident),* }) => {
+        impl Binary for $type {
+            fn write(&self, out: &mut Vec<u8>) {
+                $(self.$field.write(out);)*
+            }
+
+            fn read(input: &mut Reader<'_>) -> Option<Self> {
+                Some($type { $($field: Binary::read(input)?),* })
+            }
+        }
+    };
+}
+
+binary_enum!(TeamEnum { One = 0, Two = 1 });
+// the codes of the fields have to fit into half a byte
+binary_enum!(FieldType { OneS = 0, OneM = 1, OneL = 2, TwoS = 3, TwoM = 4, TwoL = 5, Squid = 6, Empty = 7 });
+binary_enum!(Direction { Up = 0, UpRight = 1, Right = 2, DownRight = 3, Down = 4, DownLeft = 5, Left = 6, UpLeft = 7 });
+binary_enum!(Symmetry {
+    Identity = 0, Rotate90 = 1, Rotate180 = 2, Rotate270 = 3,
+    FlipHorizontal = 4, FlipVertical = 5, Transpose = 6, AntiTranspose = 7
+});
+binary_enum!(WinReason { SwarmConnected = 0, BothConnected = 1, RoundLimit = 2, Timeout = 3, IllegalMove = 4 });
+binary_enum!(BoundType { Exact = 0, Lower = 1, Upper = 2 });
+binary_enum!(Playout { Random = 0, Heuristic = 1 });
+binary_enum!(EngineKind { AlphaBeta = 0, MonteCarlo = 1 });
+
+binary_struct!(Vector { delta_x, delta_y });
+binary_struct!(Coordinate { x, y });
+binary_struct!(Move { start, direction });
+binary_struct!(GameState { board, turn, last_move });
+binary_struct!(UndoToken { move_, target, captured, last_move, turn });
+binary_struct!(GameResult { winner, reason, team_one_score, team_two_score });
+binary_struct!(Swarm { team, members, value });
+binary_struct!(ScoreEntry { name, team, parts });
+binary_struct!(ServerResult { fragments, scores, winner, regular, reason });
+binary_struct!(Replay { states, result });
+binary_struct!(MatchResult { result, moves, move_times_ms, final_state });
+binary_struct!(Match { start, time_limit_ms });
+binary_struct!(Evaluator { swarm_size, largest_swarm_value, swarm_count, centre_distance, mobility, squid_adjacency });
+binary_struct!(TableEntry { key, bound, depth, score, best_move });
+binary_struct!(EngineConfig { kind, max_depth, exploration, playout, evaluator });
+binary_struct!(Sprt { elo0, elo1, alpha, beta });
+binary_struct!(ArenaResult { wins, draws, losses, llr, sprt_accepted });
+binary_struct!(TranspositionTable { entries, hits, misses, collisions, stores });
+
+// the number of threads depends on the machine, so it is left out and set to the default when reading
+impl Binary for Arena {
+    fn write(&self, out: &mut Vec<u8>) {
+        self.games.write(out);
+        self.time_limit_ms.write(out);
+        self.seed.write(out);
+        self.sprt.write(out);
+    }
+
+    fn read(input: &mut Reader<'_>) -> Option<Self> {
+        Some(Arena::new(Binary::read(input)?, None, Binary::read(input)?, Binary::read(input)?, Binary::read(input)?))
+    }
+}
+
+// only the states that were not returned yet are stored
+impl Binary for ReplayIterator {
+    fn write(&self, out: &mut Vec<u8>) {
+        self.states.as_slice().to_vec().write(out);
+    }
+
+    fn read(input: &mut Reader<'_>) -> Option<Self> {
+        Some(ReplayIterator { states: Vec::read(input)?.into_iter() })
+    }
+}
+
+// two fields per byte, the lower half holds the field with the even index
+impl Binary for Board {
+    fn write(&self, out: &mut Vec<u8>) {
+        let fields = PluginConstants::BOARD_WIDTH * PluginConstants::BOARD_HEIGHT;
+
+        let mut codes = Vec::with_capacity(fields);
+        for index in 0..fields {
+            self.get_field(&bitboard::coordinate_of(index)).unwrap_or(FieldType::Empty).write(&mut codes);
+        }
+        out.extend(codes.chunks(2).map(|pair| pair[0] | (pair[1] << 4)));
+    }
+
+    fn read(input: &mut Reader<'_>) -> Option<Self> {
+        let fields = PluginConstants::BOARD_WIDTH * PluginConstants::BOARD_HEIGHT;
+        let mut board = Board::empty();
+
+        for index in (0..fields).step_by(2) {
+            let byte = input.byte()?;
+            for (offset, nibble) in [byte & 0x0f, byte >> 4].into_iter().enumerate() {
+                let field = FieldType::read(&mut Reader { bytes: &[nibble] })?;
+                board.set_field(&bitboard::coordinate_of(index + offset), field);
+            }
+        }
+
+        Some(board)
+    }
+}
@@ -0,0 +1,129 @@
+use crate::plugin2026::utils::{
+    constants::PluginConstants,
+    coordinate::Coordinate,
+    direction::Direction
+};
+
+// a field (x, y) is stored in bit y * BOARD_WIDTH + x, so bit 0 is the bottom-left corner
+pub type Bitboard = u128;
+
+const WIDTH: usize = PluginConstants::BOARD_WIDTH;
+const HEIGHT: usize = PluginConstants::BOARD_HEIGHT;
+pub const FIELD_COUNT: usize = WIDTH * HEIGHT;
+
+pub const FULL: Bitboard = (1 << FIELD_COUNT) - 1;
+pub const LEFT_COLUMN: Bitboard = column_mask(0);
+pub const RIGHT_COLUMN: Bitboard = column_mask(WIDTH - 1);
+
+// same order as Direction, so that a direction can be used as index
+const DELTAS: [(isize, isize); 8] = [(0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1), (-1, 0), (-1, 1)];
+
+// every field reachable from a field in a direction, not including the field itself
+static RAYS: [[Bitboard; 8]; FIELD_COUNT] = compute_rays();
+
+const fn column_mask(x: usize) -> Bitboard {
+    let mut mask = 0;
+    let mut y = 0;
+    while y < HEIGHT {
+        mask |= 1 << (y * WIDTH + x);
+        y += 1;
+    }
+    mask
+}
+
+const fn compute_rays() -> [[Bitboard; 8]; FIELD_COUNT] {
+    let mut rays = [[0; 8]; FIELD_COUNT];
+
+    let mut index = 0;
+    while index < FIELD_COUNT {
+        let mut d = 0;
+        while d < DELTAS.len() {
+            let (dx, dy) = DELTAS[d];
+            let mut x = (index % WIDTH) as isize + dx;
+            let mut y = (index / WIDTH) as isize + dy;
+
+            while x >= 0 && x < WIDTH as isize && y >= 0 && y < HEIGHT as isize {
+                rays[index][d] |= 1 << (y as usize * WIDTH + x as usize);
+                x += dx;
+                y += dy;
+            }
+            d += 1;
+        }
+        index += 1;
+    }
+
+    rays
+}
+
+pub fn index_of(position: &Coordinate) -> Option<usize> {
+    if position.x < 0 || position.x >= WIDTH as isize || position.y < 0 || position.y >= HEIGHT as isize {
+        return None;
+    }
+
+    Some(position.y as usize * WIDTH + position.x as usize)
+}
+
+pub fn coordinate_of(index: usize) -> Coordinate {
+    Coordinate {
+        x: (index % WIDTH) as isize,
+        y: (index / WIDTH) as isize
+    }
+}
+
+pub fn bit_of(position: &Coordinate) -> Bitboard {
+    index_of(position).map_or(0, |i| 1 << i)
+}
+
+pub fn ray(index: usize, direction: &Direction) -> Bitboard {
+    RAYS[index][*direction as usize]
+}
+
+// the whole line through a field along the axis of the direction, including the field itself
+pub fn line(index: usize, direction: &Direction) -> Bitboard {
+    ray(index, direction) | ray(index, &direction.to_mirrored()) | (1 << index)
+}
+
+// the fields of a mask on one line along the direction, in the order they are passed when walking in the direction
+pub fn along(mask: Bitboard, direction: &Direction) -> Vec<usize> {
+    let (dx, dy) = DELTAS[*direction as usize];
+    let mut fields: Vec<usize> = indices(mask).collect();
+
+    if dy * (WIDTH as isize) + dx < 0 {
+        fields.reverse();
+    }
+
+    fields
+}
+
+// the mask grown by one field into all eight directions
+pub fn dilate(mask: Bitboard) -> Bitboard {
+    let horizontal = mask | ((mask << 1) & !LEFT_COLUMN) | ((mask >> 1) & !RIGHT_COLUMN);
+    (horizontal | (horizontal << WIDTH) | (horizontal >> WIDTH)) & FULL
+}
+
+pub fn flood_fill(seed: Bitboard, within: Bitboard) -> Bitboard {
+    let mut filled = seed & within;
+
+    loop {
+        let next = dilate(filled) & within;
+        if next == filled {
+            return filled;
+        }
+        filled = next;
+    }
+}
+
+pub fn indices(mut mask: Bitboard) -> impl Iterator<Item = usize> {
+    std::iter::from_fn(move || {
+        if mask == 0 {
+            return None;
+        }
+        let index = mask.trailing_zeros() as usize;
+        mask &= mask - 1;
+        Some(index)
+    })
+}
+
+pub fn coordinates(mask: Bitboard) -> impl Iterator<Item = Coordinate> {
+    indices(mask).map(coordinate_of)
+}
@@ -1,6 +1,7 @@
 use pyo3::*;
 
-#[pyclass]
+// never instantiated, pickle refers to the class by name
+#[pyclass(module = "socha._socha")]
 pub struct PluginConstants;
 
 #[pymethods]
@@ -9,4 +10,16 @@ impl PluginConstants {
     pub const BOARD_HEIGHT: usize = 10;
 
     pub const ROUND_LIMIT: usize = 30;
+
+    // fish of each team at the start, by size
+    pub const SMALL_FISH: usize = 7;
+    pub const MEDIUM_FISH: usize = 5;
+    pub const LARGE_FISH: usize = 4;
+
+    // one plane per field type, plus the team on turn and the progress of the game for states
+    pub const BOARD_PLANES: usize = 8;
+    pub const STATE_PLANES: usize = 10;
+
+    // every start field combined with every direction
+    pub const MOVE_INDICES: usize = 800;
 }
@@ -1,9 +1,12 @@
+use std::borrow::Cow;
+
 use pyo3::*;
 
-use crate::plugin2026::{utils::vector::Vector};
+use crate::plugin2026::{utils::{binary, hashing, symmetry::Symmetry, vector::Vector}};
 
-#[pyclass]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Coordinate {
     #[pyo3(get, set)]
     pub x: isize,
@@ -24,8 +27,29 @@ impl Coordinate {
     fn __repr__(&self) -> String {format!("{:?}", self)}
     fn __eq__(&self, other: &Coordinate) -> bool {self == other}
     fn __ne__(&self, other: &Coordinate) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __lt__(&self, other: &Coordinate) -> bool {self < other}
+    fn __le__(&self, other: &Coordinate) -> bool {self <= other}
+    fn __gt__(&self, other: &Coordinate) -> bool {self > other}
+    fn __ge__(&self, other: &Coordinate) -> bool {self >= other}
+    fn __copy__(&self) -> Coordinate {*self}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Coordinate {*self}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
     fn deepcopy(&self) -> Coordinate {*self}
 
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Coordinate> {
+        binary::from_bytes(data)
+    }
+
+    pub fn transform(&self, symmetry: Symmetry) -> Coordinate {
+        symmetry.transform_coordinate(self)
+    }
+
     pub fn add_vector(&self, vector: &Vector) -> Coordinate {
         Coordinate {
             x: self.x + vector.delta_x,
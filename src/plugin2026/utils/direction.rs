@@ -1,11 +1,14 @@
+use std::borrow::Cow;
+
 use pyo3::*;
 
 use crate::plugin2026::{
-    utils::vector::Vector
+    utils::{binary, hashing, symmetry::Symmetry, vector::Vector}
 };
 
-#[pyclass]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Direction {
     Up,
     UpRight,
@@ -23,8 +26,21 @@ impl Direction {
     fn __repr__(&self) -> String {format!("{:?}", self)}
     fn __eq__(&self, other: &Direction) -> bool {self == other}
     fn __ne__(&self, other: &Direction) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __copy__(&self) -> Direction {*self}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Direction {*self}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
     fn deepcopy(&self) -> Direction {*self}
 
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Direction> {
+        binary::from_bytes(data)
+    }
+
     #[staticmethod]
     pub fn from_vector(vector: &Vector) -> Option<Direction> {
         match (vector.delta_x, vector.delta_y) {
@@ -67,6 +83,10 @@ impl Direction {
         }
     }
 
+    pub fn transform(&self, symmetry: Symmetry) -> Direction {
+        Direction::from_vector(&symmetry.transform_vector(&self.to_vector())).expect("Symmetries keep directions")
+    }
+
     pub fn to_mirrored(&self) -> Direction {
         match self {
             Direction::Up => Direction::Down,
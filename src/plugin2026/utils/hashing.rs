@@ -0,0 +1,13 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+// equal values hash equal, but like python's own hashes the numbers may change between versions, so they must not be stored
+pub fn value_hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+// 0.0 and -0.0 are equal, so they have to hash equal as well
+pub fn float_bits(value: f64) -> u64 {
+    if value == 0.0 { 0 } else { value.to_bits() }
+}
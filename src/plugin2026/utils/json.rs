@@ -0,0 +1,11 @@
+use pyo3::{exceptions::PyValueError, *};
+use serde::{de::DeserializeOwned, Serialize};
+
+pub fn to_json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value).expect("Game types serialize without errors")
+}
+
+pub fn from_json<T: DeserializeOwned + PyTypeInfo>(json: &str) -> PyResult<T> {
+    serde_json::from_str(json)
+        .map_err(|e| PyValueError::new_err(format!("Invalid JSON for {}: {}", T::NAME, e)))
+}
@@ -0,0 +1,132 @@
+use std::borrow::Cow;
+
+use pyo3::*;
+
+use crate::plugin2026::{
+    field_type::FieldType,
+    utils::{
+        binary,
+        constants::PluginConstants,
+        coordinate::Coordinate,
+        hashing,
+        vector::Vector
+    }
+};
+
+// the 8 symmetries of the square board, rotations are counterclockwise
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Symmetry {
+    Identity,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    // mirrors x, so left and right are swapped
+    FlipHorizontal,
+    // mirrors y, so bottom and top are swapped
+    FlipVertical,
+    // mirrors along the diagonal from the bottom left to the top right corner
+    Transpose,
+    // mirrors along the diagonal from the top left to the bottom right corner
+    AntiTranspose
+}
+
+#[pymethods]
+impl Symmetry {
+    fn __str__(&self) -> String {format!("{:?}", self)}
+    fn __repr__(&self) -> String {format!("{:?}", self)}
+    fn __eq__(&self, other: &Symmetry) -> bool {self == other}
+    fn __ne__(&self, other: &Symmetry) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __copy__(&self) -> Symmetry {*self}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Symmetry {*self}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Symmetry> {
+        binary::from_bytes(data)
+    }
+
+    #[staticmethod]
+    pub fn all_symmetries() -> Vec<Symmetry> {
+        vec![
+            Symmetry::Identity,
+            Symmetry::Rotate90,
+            Symmetry::Rotate180,
+            Symmetry::Rotate270,
+            Symmetry::FlipHorizontal,
+            Symmetry::FlipVertical,
+            Symmetry::Transpose,
+            Symmetry::AntiTranspose
+        ]
+    }
+
+    // team one starts in the columns and team two in the rows, so swapping the axes swaps the teams
+    pub fn swaps_teams(&self) -> bool {
+        matches!(self, Symmetry::Rotate90 | Symmetry::Rotate270 | Symmetry::Transpose | Symmetry::AntiTranspose)
+    }
+
+    pub fn inverse(&self) -> Symmetry {
+        match self {
+            Symmetry::Rotate90 => Symmetry::Rotate270,
+            Symmetry::Rotate270 => Symmetry::Rotate90,
+            other => *other,
+        }
+    }
+
+    pub fn transform_vector(&self, vector: &Vector) -> Vector {
+        let (x, y) = (vector.delta_x, vector.delta_y);
+
+        let (delta_x, delta_y) = match self {
+            Symmetry::Identity => (x, y),
+            Symmetry::Rotate90 => (-y, x),
+            Symmetry::Rotate180 => (-x, -y),
+            Symmetry::Rotate270 => (y, -x),
+            Symmetry::FlipHorizontal => (-x, y),
+            Symmetry::FlipVertical => (x, -y),
+            Symmetry::Transpose => (y, x),
+            Symmetry::AntiTranspose => (-y, -x),
+        };
+
+        Vector { delta_x, delta_y }
+    }
+
+    // the same as transform_vector, turning around the center of the board instead of the origin
+    pub fn transform_coordinate(&self, position: &Coordinate) -> Coordinate {
+        let max = PluginConstants::BOARD_WIDTH as isize - 1;
+        let (x, y) = (position.x, position.y);
+
+        let (x, y) = match self {
+            Symmetry::Identity => (x, y),
+            Symmetry::Rotate90 => (max - y, x),
+            Symmetry::Rotate180 => (max - x, max - y),
+            Symmetry::Rotate270 => (y, max - x),
+            Symmetry::FlipHorizontal => (max - x, y),
+            Symmetry::FlipVertical => (x, max - y),
+            Symmetry::Transpose => (y, x),
+            Symmetry::AntiTranspose => (max - y, max - x),
+        };
+
+        Coordinate { x, y }
+    }
+
+    pub fn transform_field(&self, field: FieldType) -> FieldType {
+        if !self.swaps_teams() {
+            return field;
+        }
+
+        match field {
+            FieldType::OneS => FieldType::TwoS,
+            FieldType::OneM => FieldType::TwoM,
+            FieldType::OneL => FieldType::TwoL,
+            FieldType::TwoS => FieldType::OneS,
+            FieldType::TwoM => FieldType::OneM,
+            FieldType::TwoL => FieldType::OneL,
+            other => other,
+        }
+    }
+}
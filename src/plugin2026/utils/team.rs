@@ -1,9 +1,12 @@
+use std::borrow::Cow;
+
 use pyo3::*;
 
-use crate::plugin2026::field_type::FieldType;
+use crate::plugin2026::{field_type::FieldType, utils::{binary, hashing}};
 
-#[pyclass]
+#[pyclass(module = "socha._socha")]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TeamEnum {
     One,
     Two,
@@ -15,6 +18,19 @@ impl TeamEnum {
     fn __repr__(&self) -> String {format!("{:?}", self)}
     fn __eq__(&self, other: &TeamEnum) -> bool {self == other}
     fn __ne__(&self, other: &TeamEnum) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __copy__(&self) -> TeamEnum {*self}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> TeamEnum {*self}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<TeamEnum> {
+        binary::from_bytes(data)
+    }
 
     pub fn get_fish_types(&self) -> Vec<FieldType> {
         match self {
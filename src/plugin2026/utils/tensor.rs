@@ -0,0 +1,18 @@
+use pyo3::{
+    buffer::PyBuffer,
+    prelude::PyAnyMethods,
+    types::PyTuple,
+    *
+};
+
+// numpy is imported on first use, so it stays an optional dependency of the package
+pub fn to_numpy(py: Python<'_>, values: &[f32], shape: &[usize]) -> PyResult<PyObject> {
+    let array = py.import_bound("numpy")?.call_method1("empty", (PyTuple::new_bound(py, shape), "float32"))?;
+
+    // the values are copied once, straight into the memory numpy allocated for the array
+    let buffer = PyBuffer::<f32>::get_bound(&array)?;
+    buffer.copy_from_slice(py, values)?;
+    buffer.release(py);
+
+    Ok(array.unbind())
+}
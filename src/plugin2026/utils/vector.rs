@@ -1,6 +1,10 @@
+use std::borrow::Cow;
+
 use pyo3::*;
 
-#[pyclass]
+use crate::plugin2026::utils::{binary, hashing};
+
+#[pyclass(module = "socha._socha")]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Vector {
     #[pyo3(get, set)]
@@ -22,8 +26,21 @@ impl Vector {
     fn __repr__(&self) -> String {format!("{:?}", self)}
     fn __eq__(&self, other: &Vector) -> bool {self == other}
     fn __ne__(&self, other: &Vector) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __copy__(&self) -> Vector {*self}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Vector {*self}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
     fn deepcopy(&self) -> Vector {*self}
 
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Vector> {
+        binary::from_bytes(data)
+    }
+
     pub fn add_vector(&self, other: &Vector) -> Vector {
         Vector {
             delta_x: self.delta_x + other.delta_x,
@@ -0,0 +1,49 @@
+use crate::plugin2026::{
+    field_type::FieldType,
+    utils::bitboard::FIELD_COUNT
+};
+
+// fixed keys, so that hashes stay the same between runs and can be stored
+const SEED: u64 = 0x5EED_F00D_9124_4A5C;
+
+// one key per field and per non-empty field type (six fish types and the squid)
+static FIELD_KEYS: [[u64; 7]; FIELD_COUNT] = compute_field_keys();
+
+pub const TEAM_TWO_KEY: u64 = split_mix(SEED ^ 0xFFFF_FFFF);
+
+// not part of the position hash, but the search keys by the turn, as the round limit and the swarm check depend on it
+pub fn turn_key(turn: usize) -> u64 {
+    split_mix(TEAM_TWO_KEY ^ turn as u64)
+}
+
+const fn split_mix(state: u64) -> u64 {
+    let mut z = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+const fn compute_field_keys() -> [[u64; 7]; FIELD_COUNT] {
+    let mut keys = [[0; 7]; FIELD_COUNT];
+    let mut state = SEED;
+
+    let mut index = 0;
+    while index < FIELD_COUNT {
+        let mut field = 0;
+        while field < 7 {
+            state = split_mix(state);
+            keys[index][field] = state;
+            field += 1;
+        }
+        index += 1;
+    }
+
+    keys
+}
+
+pub fn field_key(index: usize, field: FieldType) -> u64 {
+    match field {
+        FieldType::Empty => 0,
+        _ => FIELD_KEYS[index][field as usize],
+    }
+}
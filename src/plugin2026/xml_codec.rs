@@ -0,0 +1,458 @@
+use std::borrow::Cow;
+
+use pyo3::*;
+use quick_xml::{events::Event, Reader};
+
+use crate::plugin2026::{
+    board::Board,
+    errors::XmlError,
+    field_type::FieldType,
+    game_state::GameState,
+    r#move::Move,
+    utils::{
+        binary,
+        coordinate::Coordinate,
+        direction::Direction,
+        hashing,
+        team::TeamEnum
+    }
+};
+
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScoreEntry {
+    #[pyo3(get)]
+    pub name: String,
+    #[pyo3(get)]
+    pub team: TeamEnum,
+    // one value per fragment of the result definition
+    #[pyo3(get)]
+    pub parts: Vec<i64>,
+}
+
+#[pymethods]
+impl ScoreEntry {
+    fn __repr__(&self) -> String {format!("{:?}", self)}
+    fn __eq__(&self, other: &ScoreEntry) -> bool {self == other}
+    fn __ne__(&self, other: &ScoreEntry) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __copy__(&self) -> ScoreEntry {self.clone()}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> ScoreEntry {self.clone()}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<ScoreEntry> {
+        binary::from_bytes(data)
+    }
+}
+
+// the result as it is sent by the server, in contrast to GameResult which is computed from a state
+#[pyclass(module = "socha._socha")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ServerResult {
+    #[pyo3(get)]
+    pub fragments: Vec<String>,
+    #[pyo3(get)]
+    pub scores: Vec<ScoreEntry>,
+    #[pyo3(get)]
+    pub winner: Option<TeamEnum>,
+    #[pyo3(get)]
+    pub regular: bool,
+    #[pyo3(get)]
+    pub reason: String,
+}
+
+#[pymethods]
+impl ServerResult {
+    fn __repr__(&self) -> String {format!("{:?}", self)}
+    fn __eq__(&self, other: &ServerResult) -> bool {self == other}
+    fn __ne__(&self, other: &ServerResult) -> bool {self != other}
+    fn __hash__(&self) -> u64 {hashing::value_hash(self)}
+    fn __copy__(&self) -> ServerResult {self.clone()}
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> ServerResult {self.clone()}
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Cow<'static, [u8]>,))> {binary::reduce(py, self)}
+
+    pub fn to_bytes(&self) -> Cow<'static, [u8]> {
+        binary::to_bytes(self)
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<ServerResult> {
+        binary::from_bytes(data)
+    }
+
+    pub fn score_of(&self, team: &TeamEnum) -> Option<ScoreEntry> {
+        self.scores.iter().find(|e| e.team == *team).cloned()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RoomMessage {
+    State(GameState),
+    Move(Move),
+    Result(ServerResult),
+    // every other data class, like welcomeMessage or moveRequest
+    Other(String),
+}
+
+// a namespace for the codec functions, it has no instances and pickle refers to the class by name
+#[pyclass(module = "socha._socha")]
+pub struct XmlCodec;
+
+#[pymethods]
+impl XmlCodec {
+    // returns the state, move or result of a room message, None for all other messages
+    #[staticmethod]
+    #[pyo3(name = "parse_room")]
+    fn py_parse_room(py: Python<'_>, xml: &str) -> PyResult<Option<PyObject>> {
+        Ok(match Self::parse_room(xml)?.1 {
+            RoomMessage::State(state) => Some(state.into_py(py)),
+            RoomMessage::Move(move_) => Some(move_.into_py(py)),
+            RoomMessage::Result(result) => Some(result.into_py(py)),
+            RoomMessage::Other(_) => None,
+        })
+    }
+
+    #[staticmethod]
+    pub fn room_id(xml: &str) -> Result<String, XmlError> {
+        Ok(Self::parse_room(xml)?.0)
+    }
+
+    #[staticmethod]
+    pub fn parse_state(xml: &str) -> Result<GameState, XmlError> {
+        let element = Element::parse_single(xml)?;
+        match element.name.as_str() {
+            "state" => Self::state_from(&element),
+            _ => match Self::message_from(&element)?.1 {
+                RoomMessage::State(state) => Ok(state),
+                _ => Err(XmlError::new("message does not contain a state")),
+            },
+        }
+    }
+
+    #[staticmethod]
+    pub fn parse_move(xml: &str) -> Result<Move, XmlError> {
+        let element = Element::parse_single(xml)?;
+        match element.name.as_str() {
+            "data" => Self::move_from(&element),
+            _ => match Self::message_from(&element)?.1 {
+                RoomMessage::Move(move_) => Ok(move_),
+                _ => Err(XmlError::new("message does not contain a move")),
+            },
+        }
+    }
+
+    #[staticmethod]
+    pub fn parse_result(xml: &str) -> Result<ServerResult, XmlError> {
+        let element = Element::parse_single(xml)?;
+        match element.name.as_str() {
+            "data" => Self::result_from(&element),
+            _ => match Self::message_from(&element)?.1 {
+                RoomMessage::Result(result) => Ok(result),
+                _ => Err(XmlError::new("message does not contain a result")),
+            },
+        }
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (move_, room_id=None))]
+    pub fn move_to_xml(move_: &Move, room_id: Option<&str>) -> String {
+        let data = format!(
+            "<data class=\"move\"><from x=\"{}\" y=\"{}\"/><direction>{}</direction></data>",
+            move_.start.x, move_.start.y, Self::direction_name(&move_.direction)
+        );
+
+        match room_id {
+            Some(id) => format!("<room roomId=\"{}\">{}</room>", escape(id), data),
+            None => data,
+        }
+    }
+
+    // team one always begins, the team on turn follows from the turn alone, see RulesEngine::get_team_on_turn
+    #[staticmethod]
+    pub fn state_to_xml(state: &GameState) -> String {
+        let mut xml = format!("<state class=\"state\" startTeam=\"ONE\" turn=\"{}\"><board>", state.turn);
+
+        for row in state.board.map() {
+            xml.push_str("<row>");
+            for field in row {
+                xml.push_str(&format!("<field>{}</field>", Self::field_name(&field)));
+            }
+            xml.push_str("</row>");
+        }
+        xml.push_str("</board>");
+
+        if let Some(last_move) = &state.last_move {
+            xml.push_str(&format!(
+                "<lastMove><from x=\"{}\" y=\"{}\"/><direction>{}</direction></lastMove>",
+                last_move.start.x, last_move.start.y, Self::direction_name(&last_move.direction)
+            ));
+        }
+
+        xml.push_str("</state>");
+        xml
+    }
+}
+
+// rust exclusive methods
+impl XmlCodec {
+    pub fn parse_room(xml: &str) -> Result<(String, RoomMessage), XmlError> {
+        Self::message_from(&Element::parse_single(xml)?)
+    }
+
+    pub fn message_from(room: &Element) -> Result<(String, RoomMessage), XmlError> {
+        if room.name != "room" {
+            return Err(XmlError::new(format!("expected <room>, found <{}>", room.name)));
+        }
+
+        let room_id = room.required_attribute("roomId")?.to_string();
+        let data = room.required_child("data")?;
+
+        let message = match data.required_attribute("class")? {
+            "memento" => RoomMessage::State(Self::state_from(data.required_child("state")?)?),
+            "move" => RoomMessage::Move(Self::move_from(data)?),
+            "result" => RoomMessage::Result(Self::result_from(data)?),
+            other => RoomMessage::Other(other.to_string()),
+        };
+
+        Ok((room_id, message))
+    }
+
+    pub fn state_from(state: &Element) -> Result<GameState, XmlError> {
+        // a state that starts with team two could not be represented, the teams on turn would be swapped
+        if let Some(start_team) = state.attribute("startTeam") {
+            if Self::team_from_name(start_team)? != TeamEnum::One {
+                return Err(XmlError::new("only games where team ONE starts are supported"));
+            }
+        }
+
+        let turn = state.required_attribute("turn")?
+            .parse::<usize>()
+            .map_err(|_| XmlError::new("turn is not a number"))?;
+
+        let map = state.required_child("board")?
+            .children_named("row")
+            .map(|row| row.children_named("field").map(|f| Self::field_from_name(f.text.trim())).collect())
+            .collect::<Result<Vec<Vec<FieldType>>, XmlError>>()?;
+
+        let last_move = state.child("lastMove").map(Self::move_from).transpose()?;
+
+        let board = Board::try_from(map).map_err(XmlError::new)?;
+
+        Ok(GameState::new(board, turn, last_move))
+    }
+
+    // reads <from x y/> and <direction>, which is the same for <data class="move"> and <lastMove>
+    pub fn move_from(element: &Element) -> Result<Move, XmlError> {
+        let from = element.required_child("from")?;
+        let coordinate = |name: &str| from.required_attribute(name)?
+            .parse::<isize>()
+            .map_err(|_| XmlError::new(format!("{} is not a number", name)));
+
+        let start = Coordinate::new(coordinate("x")?, coordinate("y")?);
+        let direction = Self::direction_from_name(element.required_child("direction")?.text.trim())?;
+
+        Ok(Move::new(start, direction))
+    }
+
+    pub fn result_from(data: &Element) -> Result<ServerResult, XmlError> {
+        let fragments = data.child("definition")
+            .map(|d| d.children_named("fragment").map(|f| f.attribute("name").unwrap_or_default().to_string()).collect())
+            .unwrap_or_default();
+
+        let mut scores: Vec<ScoreEntry> = vec![];
+        for entry in data.child("scores").into_iter().flat_map(|s| s.children_named("entry")) {
+            let player = entry.required_child("player")?;
+            let parts = entry.child("score")
+                .into_iter()
+                .flat_map(|s| s.children_named("part"))
+                .map(|p| p.text.trim().parse::<i64>().map_err(|_| XmlError::new("score part is not a number")))
+                .collect::<Result<Vec<i64>, XmlError>>()?;
+
+            scores.push(ScoreEntry {
+                name: player.attribute("name").unwrap_or_default().to_string(),
+                team: Self::team_from_name(player.required_attribute("team")?)?,
+                parts,
+            });
+        }
+
+        // a draw has a winner element without a team
+        let winner = data.child("winner");
+        Ok(ServerResult {
+            fragments,
+            scores,
+            winner: winner.and_then(|w| w.attribute("team")).map(Self::team_from_name).transpose()?,
+            regular: winner.and_then(|w| w.attribute("regular")).is_none_or(|r| r == "true"),
+            reason: winner.and_then(|w| w.attribute("reason")).unwrap_or_default().to_string(),
+        })
+    }
+
+    pub fn field_from_name(name: &str) -> Result<FieldType, XmlError> {
+        match name {
+            "ONE_S" => Ok(FieldType::OneS),
+            "ONE_M" => Ok(FieldType::OneM),
+            "ONE_L" => Ok(FieldType::OneL),
+            "TWO_S" => Ok(FieldType::TwoS),
+            "TWO_M" => Ok(FieldType::TwoM),
+            "TWO_L" => Ok(FieldType::TwoL),
+            "SQUID" => Ok(FieldType::Squid),
+            "EMPTY" => Ok(FieldType::Empty),
+            _ => Err(XmlError::new(format!("unknown field type {}", name))),
+        }
+    }
+
+    pub fn field_name(field: &FieldType) -> &'static str {
+        match field {
+            FieldType::OneS => "ONE_S",
+            FieldType::OneM => "ONE_M",
+            FieldType::OneL => "ONE_L",
+            FieldType::TwoS => "TWO_S",
+            FieldType::TwoM => "TWO_M",
+            FieldType::TwoL => "TWO_L",
+            FieldType::Squid => "SQUID",
+            FieldType::Empty => "EMPTY",
+        }
+    }
+
+    pub fn direction_from_name(name: &str) -> Result<Direction, XmlError> {
+        match name {
+            "UP" => Ok(Direction::Up),
+            "UP_RIGHT" => Ok(Direction::UpRight),
+            "RIGHT" => Ok(Direction::Right),
+            "DOWN_RIGHT" => Ok(Direction::DownRight),
+            "DOWN" => Ok(Direction::Down),
+            "DOWN_LEFT" => Ok(Direction::DownLeft),
+            "LEFT" => Ok(Direction::Left),
+            "UP_LEFT" => Ok(Direction::UpLeft),
+            _ => Err(XmlError::new(format!("unknown direction {}", name))),
+        }
+    }
+
+    pub fn direction_name(direction: &Direction) -> &'static str {
+        match direction {
+            Direction::Up => "UP",
+            Direction::UpRight => "UP_RIGHT",
+            Direction::Right => "RIGHT",
+            Direction::DownRight => "DOWN_RIGHT",
+            Direction::Down => "DOWN",
+            Direction::DownLeft => "DOWN_LEFT",
+            Direction::Left => "LEFT",
+            Direction::UpLeft => "UP_LEFT",
+        }
+    }
+
+    pub fn team_from_name(name: &str) -> Result<TeamEnum, XmlError> {
+        match name {
+            "ONE" => Ok(TeamEnum::One),
+            "TWO" => Ok(TeamEnum::Two),
+            _ => Err(XmlError::new(format!("unknown team {}", name))),
+        }
+    }
+}
+
+// a minimal element tree, the messages are small enough to be read at once
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Element>,
+    pub text: String,
+}
+
+impl Element {
+    // reads all top level elements, a message that was cut off is rejected
+    pub fn parse(xml: &str) -> Result<Vec<Element>, XmlError> {
+        let mut reader = Reader::from_str(xml);
+        reader.trim_text(true);
+
+        let mut roots: Vec<Element> = vec![];
+        let mut stack: Vec<Element> = vec![];
+
+        let close = |element: Element, stack: &mut Vec<Element>, roots: &mut Vec<Element>| {
+            match stack.last_mut() {
+                Some(parent) => parent.children.push(element),
+                None => roots.push(element),
+            }
+        };
+
+        loop {
+            match reader.read_event().map_err(|e| XmlError::new(e.to_string()))? {
+                Event::Start(start) => stack.push(Self::from_start(&start)?),
+                Event::Empty(start) => {
+                    let element = Self::from_start(&start)?;
+                    close(element, &mut stack, &mut roots);
+                },
+                Event::End(_) => {
+                    let element = stack.pop().ok_or_else(|| XmlError::new("unexpected closing tag"))?;
+                    close(element, &mut stack, &mut roots);
+                },
+                Event::Text(text) => {
+                    if let Some(element) = stack.last_mut() {
+                        element.text.push_str(&text.unescape().map_err(|e| XmlError::new(e.to_string()))?);
+                    }
+                },
+                Event::Eof => break,
+                _ => {},
+            }
+        }
+
+        if let Some(element) = stack.last() {
+            return Err(XmlError::new(format!("<{}> is not closed", element.name)));
+        }
+
+        Ok(roots)
+    }
+
+    pub fn parse_single(xml: &str) -> Result<Element, XmlError> {
+        Self::parse(xml)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| XmlError::new("message is empty"))
+    }
+
+    fn from_start(start: &quick_xml::events::BytesStart) -> Result<Element, XmlError> {
+        let mut attributes: Vec<(String, String)> = vec![];
+        for attribute in start.attributes() {
+            let attribute = attribute.map_err(|e| XmlError::new(e.to_string()))?;
+            let value = attribute.unescape_value().map_err(|e| XmlError::new(e.to_string()))?;
+            attributes.push((String::from_utf8_lossy(attribute.key.as_ref()).to_string(), value.to_string()));
+        }
+
+        Ok(Element {
+            name: String::from_utf8_lossy(start.name().as_ref()).to_string(),
+            attributes,
+            children: vec![],
+            text: String::new(),
+        })
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.iter().find(|(k, _)| k == name).map(|(_, v)| v.as_str())
+    }
+
+    pub fn required_attribute(&self, name: &str) -> Result<&str, XmlError> {
+        self.attribute(name)
+            .ok_or_else(|| XmlError::new(format!("<{}> has no attribute {}", self.name, name)))
+    }
+
+    pub fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|c| c.name == name)
+    }
+
+    pub fn required_child(&self, name: &str) -> Result<&Element, XmlError> {
+        self.child(name)
+            .ok_or_else(|| XmlError::new(format!("<{}> has no child <{}>", self.name, name)))
+    }
+
+    pub fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> {
+        self.children.iter().filter(move |c| c.name == name)
+    }
+}
+
+fn escape(value: &str) -> String {
+    quick_xml::escape::escape(value).to_string()
+}